@@ -0,0 +1,479 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::CrossoverParameters,
+    evaluation::MaybeSync,
+    individual::Individual,
+    mutation::MutationParameters,
+    optimizer::run_loop,
+    replacement::Replacement,
+    selection::{SelectionMethod, SelectionParameters},
+    statistics::Statistics,
+    termination::{Outcome, StopCondition},
+    GeneticAlgorithm,
+};
+
+/// Parameters of the operators a [`GeneticAlgorithm`] has been built
+/// with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operators {
+    selection: SelectionParameters,
+    crossover: CrossoverParameters,
+    mutation: MutationParameters,
+    replacement: Replacement,
+}
+
+impl Operators {
+    pub fn selection(&self) -> &SelectionParameters {
+        &self.selection
+    }
+
+    pub fn crossover(&self) -> &CrossoverParameters {
+        &self.crossover
+    }
+
+    pub fn mutation(&self) -> &MutationParameters {
+        &self.mutation
+    }
+
+    pub fn replacement(&self) -> Replacement {
+        self.replacement
+    }
+}
+
+/// Snapshot of a [`GeneticAlgorithm`] run, taken in between generations,
+/// from which the run can be resumed (see [`GeneticAlgorithm::resume()`]).
+///
+/// With the `serde` feature enabled, it's (de)serializable as long as the
+/// genes and the random number generator are.
+///
+/// Each checkpoint carries statistics of all the generations evolved so
+/// far - the resumed run reports them in its [`Outcome::history()`] and
+/// replays [`MutationMethod::update()`] over them - so checkpoints grow
+/// linearly with the run, and taking one every `interval` generations
+/// copies O(generations² / interval) statistics in total; for very long
+/// runs, make the interval proportionally long.
+///
+/// [`MutationMethod::update()`]: crate::mutation::MutationMethod::update
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint<G, R> {
+    operators: Operators,
+    generation: usize,
+    population: Vec<Chromosome<G>>,
+    history: Vec<Statistics>,
+    rng: R,
+}
+
+impl<G, R> Checkpoint<G, R> {
+    /// Operators of the algorithm this checkpoint has been taken from.
+    pub fn operators(&self) -> &Operators {
+        &self.operators
+    }
+
+    /// Index of the generation that's going to be evolved next.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Chromosomes of the population that's going to be evolved next.
+    pub fn population(&self) -> &[Chromosome<G>] {
+        &self.population
+    }
+
+    /// Statistics of each generation evolved so far, oldest first.
+    pub fn history(&self) -> &[Statistics] {
+        &self.history
+    }
+
+    /// State of the random number generator.
+    pub fn rng(&self) -> &R {
+        &self.rng
+    }
+}
+
+impl<S, G> GeneticAlgorithm<S, G>
+where
+    S: SelectionMethod,
+    G: Clone,
+{
+    pub fn operators(&self) -> Operators {
+        Operators {
+            selection: self.selection_method.parameters(),
+            crossover: self.crossover_method.parameters(),
+            mutation: self.mutation_method.parameters(),
+            replacement: self.replacement,
+        }
+    }
+
+    /// Like [`Optimizer::run()`](crate::optimizer::Optimizer::run), but
+    /// also calls `on_checkpoint` every `interval` generations, so that
+    /// the run can be saved and resumed later.
+    ///
+    /// Since individuals get re-created out of their chromosomes, their
+    /// fitness has to be computable from the chromosomes alone.
+    pub fn run_with_checkpoints<I, R>(
+        &mut self,
+        rng: &mut R,
+        population: Vec<I>,
+        stop: &StopCondition,
+        interval: usize,
+        on_checkpoint: impl FnMut(&Checkpoint<G, R>),
+    ) -> Outcome<I>
+    where
+        I: Individual<Gene = G> + MaybeSync,
+        R: rand::RngCore + Clone,
+    {
+        self.run_from(rng, population, Vec::new(), stop, interval, on_checkpoint)
+    }
+
+    /// Continues the run saved in `checkpoint` exactly as if it's never
+    /// been interrupted, given the same `stop` condition.
+    ///
+    /// This algorithm has to be freshly built with the same operators as
+    /// the interrupted one (panics if they differ); state of its mutation
+    /// method (if it has any, e.g.
+    /// [`ScheduledMutation`](crate::mutation::ScheduledMutation)) is
+    /// rebuilt by replaying [`MutationMethod::update()`] over the saved
+    /// history.
+    ///
+    /// Note that [`StopCondition::TimeBudget`] starts counting from zero
+    /// again.
+    ///
+    /// [`MutationMethod::update()`]: crate::mutation::MutationMethod::update
+    pub fn resume<I, R>(
+        &mut self,
+        checkpoint: Checkpoint<G, R>,
+        stop: &StopCondition,
+        interval: usize,
+        on_checkpoint: impl FnMut(&Checkpoint<G, R>),
+    ) -> Outcome<I>
+    where
+        I: Individual<Gene = G> + MaybeSync,
+        R: rand::RngCore + Clone,
+    {
+        assert_eq!(
+            checkpoint.operators,
+            self.operators(),
+            "checkpoint has been taken with different operators",
+        );
+
+        let Checkpoint { generation, population, history, mut rng, .. } = checkpoint;
+
+        assert!(generation >= history.len());
+
+        let first_generation = generation - history.len();
+
+        for (offset, statistics) in history.iter().enumerate() {
+            self.mutation_method.update(first_generation + offset, statistics);
+        }
+
+        self.generation = generation;
+
+        let population = population.into_iter().map(I::create).collect();
+
+        self.run_from(&mut rng, population, history, stop, interval, on_checkpoint)
+    }
+
+    fn run_from<I, R>(
+        &mut self,
+        rng: &mut R,
+        population: Vec<I>,
+        history: Vec<Statistics>,
+        stop: &StopCondition,
+        interval: usize,
+        mut on_checkpoint: impl FnMut(&Checkpoint<G, R>),
+    ) -> Outcome<I>
+    where
+        I: Individual<Gene = G> + MaybeSync,
+        R: rand::RngCore + Clone,
+    {
+        assert!(interval > 0);
+
+        run_loop(self, rng, population, history, stop, |ga, rng, population, history| {
+            if history.len() % interval == 0 {
+                on_checkpoint(&Checkpoint {
+                    operators: ga.operators(),
+                    generation: ga.generation,
+                    population: population
+                        .iter()
+                        .map(|individual| individual.chromosome().clone())
+                        .collect(),
+                    history: history.to_vec(),
+                    rng: rng.clone(),
+                });
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crossover::{CrossoverMethod, UniformCrossover},
+        individual::TestIndividual,
+        mutation::{GaussianMutation, MutationSchedule, ScheduledMutation},
+        selection::RouletteWheelSelection,
+        termination::StopReason,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    type TestCheckpoint = Checkpoint<f32, ChaCha8Rng>;
+
+    // Uses the 1/5th rule, so that resuming depends on the mutation's
+    // state being rebuilt properly
+    fn ga() -> GeneticAlgorithm<RouletteWheelSelection> {
+        GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            ScheduledMutation::new(
+                GaussianMutation::new(0.5, 0.5),
+                MutationSchedule::OneFifthRule { window: 3, factor: 0.82 },
+            ),
+        )
+        .with_replacement(Replacement::Generational { elitism: 1 })
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::create(vec![0.0, 0.0, 0.0].into_iter().collect()),
+            TestIndividual::create(vec![1.0, 1.0, 1.0].into_iter().collect()),
+            TestIndividual::create(vec![1.0, 2.0, 1.0].into_iter().collect()),
+            TestIndividual::create(vec![1.0, 2.0, 4.0].into_iter().collect()),
+        ]
+    }
+
+    /// Runs for 30 generations from scratch, returning the outcome along
+    /// with all the checkpoints taken.
+    fn run() -> (Outcome<TestIndividual>, Vec<TestCheckpoint>) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut checkpoints = Vec::new();
+
+        let outcome = ga().run_with_checkpoints(
+            &mut rng,
+            population(),
+            &StopCondition::MaxGenerations(30),
+            10,
+            |checkpoint| checkpoints.push(checkpoint.clone()),
+        );
+
+        (outcome, checkpoints)
+    }
+
+    /// Resumes the run from `checkpoint`, returning the outcome along with
+    /// all the further checkpoints taken.
+    fn resume(checkpoint: TestCheckpoint) -> (Outcome<TestIndividual>, Vec<TestCheckpoint>) {
+        let mut checkpoints = Vec::new();
+
+        let outcome = ga().resume(
+            checkpoint,
+            &StopCondition::MaxGenerations(30),
+            10,
+            |checkpoint: &TestCheckpoint| checkpoints.push(checkpoint.clone()),
+        );
+
+        (outcome, checkpoints)
+    }
+
+    fn assert_same_outcomes(actual: &Outcome<TestIndividual>, expected: &Outcome<TestIndividual>) {
+        assert_eq!(actual.population(), expected.population());
+        assert_eq!(actual.history(), expected.history());
+        assert_eq!(actual.stop_reasons(), expected.stop_reasons());
+    }
+
+    #[test]
+    fn checkpoints_every_interval() {
+        let (outcome, checkpoints) = run();
+
+        assert_eq!(outcome.stop_reasons(), &[StopReason::MaxGenerations]);
+
+        // There's no checkpoint after the 30th generation, since the run
+        // stops right there
+        let generations: Vec<_> = checkpoints.iter().map(Checkpoint::generation).collect();
+
+        assert_eq!(generations, vec![10, 20]);
+
+        for checkpoint in &checkpoints {
+            assert_eq!(checkpoint.history(), &outcome.history()[..checkpoint.generation()]);
+            assert_eq!(checkpoint.population().len(), 4);
+        }
+    }
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_one() {
+        let (outcome, checkpoints) = run();
+        let (resumed_outcome, resumed_checkpoints) = resume(checkpoints[0].clone());
+
+        assert_same_outcomes(&resumed_outcome, &outcome);
+
+        assert_eq!(resumed_checkpoints.len(), 1);
+        assert_eq!(resumed_checkpoints[0].generation(), checkpoints[1].generation());
+        assert_eq!(resumed_checkpoints[0].population(), checkpoints[1].population());
+        assert_eq!(resumed_checkpoints[0].rng(), checkpoints[1].rng());
+    }
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_one_after_mutation_has_adapted() {
+        let (outcome, checkpoints) = run();
+
+        assert_same_outcomes(&resume(checkpoints[1].clone()).0, &outcome);
+    }
+
+    #[test]
+    fn records_operators() {
+        let (_, checkpoints) = run();
+
+        // State the mutation has built up isn't a part of its parameters
+        for checkpoint in &checkpoints {
+            assert_eq!(checkpoint.operators(), &ga().operators());
+        }
+
+        assert_eq!(checkpoints[0].operators().selection(), &SelectionParameters::RouletteWheel);
+        assert_eq!(checkpoints[0].operators().crossover(), &CrossoverParameters::Uniform);
+
+        assert_eq!(
+            checkpoints[0].operators().mutation(),
+            &MutationParameters::Scheduled {
+                initial: GaussianMutation::new(0.5, 0.5),
+                schedule: MutationSchedule::OneFifthRule { window: 3, factor: 0.82 },
+            },
+        );
+
+        assert_eq!(
+            checkpoints[0].operators().replacement(),
+            Replacement::Generational { elitism: 1 },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different operators")]
+    fn resuming_with_different_replacement_panics() {
+        let (_, checkpoints) = run();
+
+        let _: Outcome<TestIndividual> = ga()
+            .with_replacement(Replacement::Generational { elitism: 2 })
+            .resume(
+                checkpoints[0].clone(),
+                &StopCondition::MaxGenerations(30),
+                10,
+                |_: &TestCheckpoint| (),
+            );
+    }
+
+    #[test]
+    #[should_panic(expected = "different operators")]
+    fn resuming_with_different_mutation_panics() {
+        let (_, checkpoints) = run();
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.5, 0.5),
+        )
+        .with_replacement(Replacement::Generational { elitism: 1 });
+
+        let _: Outcome<TestIndividual> = ga.resume(
+            checkpoints[0].clone(),
+            &StopCondition::MaxGenerations(30),
+            10,
+            |_: &TestCheckpoint| (),
+        );
+    }
+
+    /// Crossover defined outside of this crate, picking either parent as a
+    /// whole.
+    struct PickingCrossover {
+        chance_a: f32,
+    }
+
+    impl CrossoverMethod for PickingCrossover {
+        fn crossover(
+            &self,
+            rng: &mut dyn rand::RngCore,
+            parent_a: &Chromosome,
+            parent_b: &Chromosome,
+        ) -> Chromosome {
+            use rand::Rng;
+
+            if rng.gen_bool(self.chance_a as _) {
+                parent_a.clone()
+            } else {
+                parent_b.clone()
+            }
+        }
+
+        fn parameters(&self) -> CrossoverParameters {
+            CrossoverParameters::Custom(format!("picking(chance_a={})", self.chance_a))
+        }
+    }
+
+    fn run_with_picking_crossover(chance_a: f32) -> Vec<TestCheckpoint> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut checkpoints = Vec::new();
+
+        let _: Outcome<TestIndividual> = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            PickingCrossover { chance_a },
+            GaussianMutation::new(0.5, 0.5),
+        )
+        .run_with_checkpoints(
+            &mut rng,
+            population(),
+            &StopCondition::MaxGenerations(20),
+            10,
+            |checkpoint| checkpoints.push(checkpoint.clone()),
+        );
+
+        checkpoints
+    }
+
+    #[test]
+    fn resumes_with_same_custom_operator() {
+        let checkpoints = run_with_picking_crossover(0.5);
+
+        let outcome: Outcome<TestIndividual> = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            PickingCrossover { chance_a: 0.5 },
+            GaussianMutation::new(0.5, 0.5),
+        )
+        .resume(
+            checkpoints[0].clone(),
+            &StopCondition::MaxGenerations(20),
+            10,
+            |_: &TestCheckpoint| (),
+        );
+
+        assert_eq!(outcome.generations(), 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "different operators")]
+    fn resuming_with_differently_configured_custom_operator_panics() {
+        let checkpoints = run_with_picking_crossover(0.5);
+
+        let _: Outcome<TestIndividual> = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            PickingCrossover { chance_a: 0.75 },
+            GaussianMutation::new(0.5, 0.5),
+        )
+        .resume(
+            checkpoints[0].clone(),
+            &StopCondition::MaxGenerations(20),
+            10,
+            |_: &TestCheckpoint| (),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn resumed_run_matches_uninterrupted_one_after_serialization() {
+        let (outcome, checkpoints) = run();
+
+        let checkpoint = serde_json::to_string(&checkpoints[0]).unwrap();
+        let checkpoint: TestCheckpoint = serde_json::from_str(&checkpoint).unwrap();
+
+        assert_same_outcomes(&resume(checkpoint).0, &outcome);
+    }
+}
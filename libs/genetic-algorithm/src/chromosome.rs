@@ -1,41 +1,89 @@
+/// Genes of an individual; they're real numbers by default, but any
+/// other type (e.g. `bool` for bit strings or `usize` for permutations)
+/// works as well, provided there are operators that know how to handle
+/// it.
 #[derive(Clone, Debug)]
-pub struct Chromosome {
-    genes: Vec<f32>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chromosome<G = f32> {
+    genes: Vec<G>,
+
+    /// Strategy parameters (mutation step sizes, σ) evolved alongside
+    /// the genes - either one per gene or a single global one; empty
+    /// unless a self-adaptive mutation method is used.
+    strategy: Vec<f32>,
 }
 
-impl Chromosome {
+impl<G> Chromosome<G> {
     pub fn len(&self) -> usize {
         self.genes.len()
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &G> {
         self.genes.iter()
     }
 
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut G> {
         self.genes.iter_mut()
     }
+
+    /// Swaps genes at positions `a` and `b`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.genes.swap(a, b);
+    }
+
+    /// Reverses order of genes within `range`.
+    pub fn reverse(&mut self, range: std::ops::Range<usize>) {
+        self.genes[range].reverse();
+    }
+
+    pub fn strategy(&self) -> &[f32] {
+        &self.strategy
+    }
+
+    pub fn set_strategy(&mut self, strategy: Vec<f32>) {
+        self.strategy = strategy;
+    }
+
+    /// Passes parents' strategy parameters down to this child, using
+    /// intermediate recombination (i.e. averaging them).
+    pub(crate) fn inherit_strategy(&mut self, parent_a: &Self, parent_b: &Self) {
+        self.strategy = if parent_a.strategy.len() == parent_b.strategy.len() {
+            parent_a
+                .strategy
+                .iter()
+                .zip(parent_b.strategy.iter())
+                .map(|(a, b)| (a + b) / 2.0)
+                .collect()
+        } else {
+            parent_a.strategy.clone()
+        };
+    }
 }
 
-impl std::ops::Index<usize> for Chromosome {
-    type Output = f32;
+impl<G> std::ops::Index<usize> for Chromosome<G> {
+    type Output = G;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.genes[index]
     }
 }
 
-impl std::iter::FromIterator<f32> for Chromosome {
-    fn from_iter<T: IntoIterator<Item = f32>>(iter: T) -> Self {
+impl<G> std::iter::FromIterator<G> for Chromosome<G> {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
         Self {
             genes: iter.into_iter().collect(),
+            strategy: Vec::new(),
         }
     }
 }
 
-impl IntoIterator for Chromosome {
-    type Item = f32;
-    type IntoIter = std::vec::IntoIter<f32>;
+impl<G> IntoIterator for Chromosome<G> {
+    type Item = G;
+    type IntoIter = std::vec::IntoIter<G>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.genes.into_iter()
@@ -48,6 +96,9 @@ impl PartialEq for Chromosome {
         approx::relative_eq!(
             self.genes.as_slice(),
             other.genes.as_slice(),
+        ) && approx::relative_eq!(
+            self.strategy.as_slice(),
+            other.strategy.as_slice(),
         )
     }
 }
@@ -58,6 +109,7 @@ mod tests {
     fn chromosome() -> Chromosome {
         Chromosome {
             genes: vec![3.0, 1.0, 2.0],
+            strategy: Vec::new(),
         }
     }
 
@@ -111,6 +163,7 @@ mod tests {
         fn test() {
             let chromosome = Chromosome {
                 genes: vec![3.0, 1.0, 2.0],
+                strategy: Vec::new(),
             };
 
             assert_eq!(chromosome[0], 3.0);
@@ -142,6 +195,7 @@ mod tests {
         fn test() {
             let chromosome = Chromosome {
                 genes: vec![3.0, 1.0, 2.0],
+                strategy: Vec::new(),
             };
 
             let genes: Vec<_> = chromosome.into_iter().collect();
@@ -152,4 +206,32 @@ mod tests {
             assert_eq!(genes[2], 2.0);
         }
     }
+
+    mod inherit_strategy {
+        use super::*;
+
+        fn parent(strategy: Vec<f32>) -> Chromosome {
+            let mut parent = chromosome();
+            parent.set_strategy(strategy);
+            parent
+        }
+
+        #[test]
+        fn averages_parents_strategies() {
+            let mut child = chromosome();
+
+            child.inherit_strategy(&parent(vec![1.0, 2.0]), &parent(vec![3.0, 2.0]));
+
+            assert_eq!(child.strategy(), &[2.0, 2.0]);
+        }
+
+        #[test]
+        fn given_mismatched_strategies_takes_the_first_one() {
+            let mut child = chromosome();
+
+            child.inherit_strategy(&parent(vec![1.0]), &parent(Vec::new()));
+
+            assert_eq!(child.strategy(), &[1.0]);
+        }
+    }
 }
@@ -0,0 +1,363 @@
+use crate::{
+    chromosome::Chromosome,
+    evaluation::{evaluate, Evaluated, MaybeSync},
+    individual::Individual,
+    optimizer::Optimizer,
+    statistics::Statistics,
+};
+use nalgebra::{DMatrix, DVector};
+use rand_distr::{Distribution, StandardNormal};
+
+/// Covariance matrix adaptation evolution strategy (CMA-ES): samples
+/// chromosomes from a multivariate normal distribution and, based on how
+/// fit the samples turned out, moves the distribution's mean towards the
+/// fittest ones while learning its shape (covariance) and size (step
+/// size, σ) - which makes it handle correlated, badly scaled genes (such
+/// as neural network weights) way better than a plain GA.
+///
+/// The distribution is initialized lazily, out of the first population
+/// passed to `evolve()`; afterwards, `evolve()` expects to be given the
+/// population it returned the last time (with fitness filled in). Its
+/// size (λ) determines how many samples are drawn each generation.
+///
+/// Follows "The CMA Evolution Strategy: A Tutorial" by N. Hansen.
+#[derive(Clone, Debug)]
+pub struct CmaEs {
+    initial_sigma: f32,
+    state: Option<State>,
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    mean: DVector<f64>,
+    sigma: f64,
+    covariance: DMatrix<f64>,
+
+    /// Eigenvectors of the covariance matrix (B).
+    eigenvectors: DMatrix<f64>,
+
+    /// Square roots of eigenvalues of the covariance matrix (D).
+    eigenvalues: DVector<f64>,
+
+    /// Evolution path of the covariance matrix (p_c).
+    path_c: DVector<f64>,
+
+    /// Evolution path of the step size (p_σ).
+    path_sigma: DVector<f64>,
+
+    generation: usize,
+}
+
+impl CmaEs {
+    pub fn new(initial_sigma: f32) -> Self {
+        assert!(initial_sigma > 0.0);
+
+        Self {
+            initial_sigma,
+            state: None,
+        }
+    }
+
+    /// Current mean of the distribution, if it's been initialized yet.
+    pub fn mean(&self) -> Option<Chromosome> {
+        self.state
+            .as_ref()
+            .map(|state| state.mean.iter().map(|&gene| gene as f32).collect())
+    }
+
+    /// Current step size (σ), if the distribution's been initialized yet.
+    pub fn sigma(&self) -> Option<f32> {
+        self.state.as_ref().map(|state| state.sigma as f32)
+    }
+}
+
+/// Strategy parameters that depend on the dimension (`n`) and the
+/// population size (λ).
+struct Parameters {
+    weights: Vec<f64>,
+    mu_eff: f64,
+    c_c: f64,
+    c_sigma: f64,
+    c_1: f64,
+    c_mu: f64,
+    d_sigma: f64,
+    chi_n: f64,
+}
+
+impl Parameters {
+    fn new(n: usize, lambda: usize) -> Self {
+        let n = n as f64;
+        let mu = (lambda / 2).max(1);
+
+        let weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+
+        let sum: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.into_iter().map(|w| w / sum).collect();
+        let mu_eff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let c_c = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let c_sigma = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let c_1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+
+        let c_mu = (1.0 - c_1)
+            .min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff));
+
+        let d_sigma =
+            1.0 + 2.0 * (((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + c_sigma;
+
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        Self { weights, mu_eff, c_c, c_sigma, c_1, c_mu, d_sigma, chi_n }
+    }
+}
+
+impl State {
+    fn new(mean: DVector<f64>, sigma: f64) -> Self {
+        let n = mean.len();
+
+        Self {
+            mean,
+            sigma,
+            covariance: DMatrix::identity(n, n),
+            eigenvectors: DMatrix::identity(n, n),
+            eigenvalues: DVector::from_element(n, 1.0),
+            path_c: DVector::zeros(n),
+            path_sigma: DVector::zeros(n),
+            generation: 0,
+        }
+    }
+
+    /// Moves the distribution towards `fittest` (fittest first).
+    fn update(&mut self, params: &Parameters, fittest: &[DVector<f64>]) {
+        let n = self.mean.len() as f64;
+
+        let steps: Vec<DVector<f64>> = fittest
+            .iter()
+            .map(|x| (x - &self.mean) / self.sigma)
+            .collect();
+
+        let step = steps
+            .iter()
+            .zip(&params.weights)
+            .fold(DVector::zeros(self.mean.len()), |sum, (y, w)| sum + y * *w);
+
+        self.mean += &step * self.sigma;
+
+        // C^(-1/2) = B * D^(-1) * B^T
+        let inv_sqrt_covariance = &self.eigenvectors
+            * DMatrix::from_diagonal(&self.eigenvalues.map(|d| 1.0 / d))
+            * self.eigenvectors.transpose();
+
+        self.path_sigma = &self.path_sigma * (1.0 - params.c_sigma)
+            + inv_sqrt_covariance * &step
+                * (params.c_sigma * (2.0 - params.c_sigma) * params.mu_eff).sqrt();
+
+        self.generation += 1;
+
+        let norm = self.path_sigma.norm()
+            / (1.0 - (1.0 - params.c_sigma).powi(2 * self.generation as i32)).sqrt();
+
+        let h_sigma = if norm / params.chi_n < 1.4 + 2.0 / (n + 1.0) { 1.0 } else { 0.0 };
+
+        self.path_c = &self.path_c * (1.0 - params.c_c)
+            + &step * (h_sigma * (params.c_c * (2.0 - params.c_c) * params.mu_eff).sqrt());
+
+        let rank_one = &self.path_c * self.path_c.transpose()
+            + &self.covariance * ((1.0 - h_sigma) * params.c_c * (2.0 - params.c_c));
+
+        let rank_mu = steps
+            .iter()
+            .zip(&params.weights)
+            .fold(DMatrix::zeros(self.mean.len(), self.mean.len()), |sum, (y, w)| {
+                sum + y * y.transpose() * *w
+            });
+
+        self.covariance = &self.covariance * (1.0 - params.c_1 - params.c_mu)
+            + rank_one * params.c_1
+            + rank_mu * params.c_mu;
+
+        self.sigma *=
+            ((params.c_sigma / params.d_sigma) * (self.path_sigma.norm() / params.chi_n - 1.0))
+                .exp();
+
+        self.decompose();
+    }
+
+    fn decompose(&mut self) {
+        // Enforces symmetry, which rounding errors tend to break
+        self.covariance = (&self.covariance + self.covariance.transpose()) * 0.5;
+
+        let eigen = self.covariance.clone().symmetric_eigen();
+
+        self.eigenvectors = eigen.eigenvectors;
+        self.eigenvalues = eigen.eigenvalues.map(|value| value.max(1e-20).sqrt());
+    }
+
+    /// Draws a chromosome from the distribution: `m + σ * B * D * z`.
+    fn sample(&self, rng: &mut dyn rand::RngCore) -> Chromosome {
+        let z = DVector::from_fn(self.mean.len(), |_, _| {
+            let z: f64 = StandardNormal.sample(rng);
+            z
+        });
+
+        let y = &self.eigenvectors * z.component_mul(&self.eigenvalues);
+        let x = &self.mean + y * self.sigma;
+
+        x.iter().map(|&gene| gene as f32).collect()
+    }
+}
+
+impl<I> Optimizer<I> for CmaEs
+where
+    I: Individual<Gene = f32> + MaybeSync,
+{
+    fn evolve(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: &[I],
+    ) -> (Vec<I>, Statistics)
+    {
+        assert!(!population.is_empty());
+
+        let population = evaluate(population);
+        let statistics = Statistics::new(population.iter().map(Evaluated::fitness));
+        let dimensions = population[0].individual().chromosome().len();
+        let params = Parameters::new(dimensions, population.len());
+
+        let mut population: Vec<_> = population
+            .iter()
+            .map(|individual| (individual.fitness(), individual))
+            .collect();
+
+        population.sort_by(|(a, _), (b, _)| {
+            b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let fittest: Vec<DVector<f64>> = population
+            .iter()
+            .take(params.weights.len())
+            .map(|(_, individual)| {
+                let chromosome = individual.individual().chromosome();
+                DVector::from_iterator(chromosome.len(), chromosome.iter().map(|&g| g as f64))
+            })
+            .collect();
+
+        match &mut self.state {
+            Some(state) => {
+                assert_eq!(state.mean.len(), fittest[0].len());
+                state.update(&params, &fittest);
+            }
+
+            None => {
+                // The first population doesn't come from our distribution,
+                // so we just start from the weighted mean of its fittest
+                // individuals
+                let mean = fittest
+                    .iter()
+                    .zip(&params.weights)
+                    .fold(DVector::zeros(fittest[0].len()), |sum, (x, w)| sum + x * *w);
+
+                self.state = Some(State::new(mean, self.initial_sigma as f64));
+            }
+        }
+
+        let state = self.state.as_ref().unwrap();
+
+        let new_population = (0..population.len())
+            .map(|_| I::create(state.sample(rng)))
+            .collect();
+
+        (new_population, statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Fitness = -(distance to the optimum), where the optimum lies at
+    /// `[1.0, 2.0, ...]` and each gene's scaled differently, so that the
+    /// covariance has something to learn.
+    #[derive(Debug)]
+    struct Ellipsoid(Chromosome);
+
+    impl Individual for Ellipsoid {
+        type Gene = f32;
+
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+
+        fn fitness(&self) -> f32 {
+            -self
+                .0
+                .iter()
+                .enumerate()
+                .map(|(idx, gene)| {
+                    let scale = 10f32.powi(idx as i32);
+                    scale * (gene - (idx + 1) as f32).powi(2)
+                })
+                .sum::<f32>()
+        }
+    }
+
+    fn population(size: usize) -> Vec<Ellipsoid> {
+        (0..size)
+            .map(|_| Ellipsoid::create(vec![0.0; 4].into_iter().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn initializes_lazily() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut cma_es = CmaEs::new(0.5);
+
+        assert!(cma_es.mean().is_none());
+
+        let (population, _) = cma_es.evolve(&mut rng, &population(8));
+
+        assert_eq!(population.len(), 8);
+        assert_eq!(cma_es.mean().unwrap(), vec![0.0; 4].into_iter().collect());
+        assert_eq!(cma_es.sigma(), Some(0.5));
+    }
+
+    #[test]
+    fn converges_to_the_optimum() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut cma_es = CmaEs::new(0.5);
+        let mut population = population(8);
+        let mut statistics = None;
+
+        for _ in 0..150 {
+            let (new_population, new_statistics) = cma_es.evolve(&mut rng, &population);
+
+            population = new_population;
+            statistics = Some(new_statistics);
+        }
+
+        let mean = cma_es.mean().unwrap();
+
+        for (idx, gene) in mean.iter().enumerate() {
+            approx::assert_abs_diff_eq!(*gene, (idx + 1) as f32, epsilon = 1e-3);
+        }
+
+        assert!(statistics.unwrap().max_fitness() > -1e-4);
+        assert!(cma_es.sigma().unwrap() < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn evolve_with_empty_population_panics() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        CmaEs::new(0.5).evolve(&mut rng, &Vec::<Ellipsoid>::new());
+    }
+}
@@ -1,16 +1,55 @@
+mod arithmetic;
+mod blend;
+mod k_point;
+mod order;
+mod pmx;
+mod simulated_binary;
+mod single_point;
 mod uniform;
 
-pub use self::uniform::*;
+pub use self::{
+    arithmetic::*,
+    blend::*,
+    k_point::*,
+    order::*,
+    pmx::*,
+    simulated_binary::*,
+    single_point::*,
+    uniform::*,
+};
 
 use crate::{
     chromosome::Chromosome,
 };
 
-pub trait CrossoverMethod {
+pub trait CrossoverMethod<G = f32> {
     fn crossover(
         &self,
         rng: &mut dyn rand::RngCore,
-        parent_a: &Chromosome,
-        parent_b: &Chromosome,
-    ) -> Chromosome;
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>;
+
+    /// Describes how this method mixes parents; crossovers defined
+    /// outside of this crate return [`CrossoverParameters::Custom`] with a
+    /// description that changes whenever their settings do.
+    fn parameters(&self) -> CrossoverParameters;
+}
+
+/// Parameters of a [`CrossoverMethod`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CrossoverParameters {
+    Arithmetic(ArithmeticCrossover),
+    Blend(BlendCrossover),
+    KPoint(KPointCrossover),
+    Order,
+    Pmx,
+    SimulatedBinary(SimulatedBinaryCrossover),
+    SinglePoint,
+    Uniform,
+
+    /// Method defined outside of this crate, along with a description of
+    /// its settings (compared as-is when resuming from a checkpoint).
+    Custom(String),
 }
@@ -0,0 +1,85 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// Creates a child whose genes are weighted averages of its parents'
+/// genes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArithmeticCrossover {
+    /// Weight of `parent_a`'s genes:
+    /// - 0.0 = child is a copy of `parent_b`
+    /// - 0.5 = child sits right in between its parents
+    /// - 1.0 = child is a copy of `parent_a`
+    alpha: f32,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!((0.0..=1.0).contains(&alpha));
+
+        Self { alpha }
+    }
+}
+
+impl CrossoverMethod for ArithmeticCrossover {
+    fn crossover(
+        &self,
+        _rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome
+    {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| self.alpha * a + (1.0 - self.alpha) * b)
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::Arithmetic(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(alpha: f32) -> Vec<f32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+        let parent_b: Chromosome = vec![3.0, -2.0, 3.0].into_iter().collect();
+
+        ArithmeticCrossover::new(alpha)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn given_zero_alpha_copies_parent_b() {
+        approx::assert_relative_eq!(actual(0.0).as_slice(), [3.0, -2.0, 3.0].as_ref());
+    }
+
+    #[test]
+    fn given_half_alpha_averages_parents() {
+        approx::assert_relative_eq!(actual(0.5).as_slice(), [2.0, 0.0, 3.0].as_ref());
+    }
+
+    #[test]
+    fn given_max_alpha_copies_parent_a() {
+        approx::assert_relative_eq!(actual(1.0).as_slice(), [1.0, 2.0, 3.0].as_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_with_alpha_out_of_range_panics() {
+        ArithmeticCrossover::new(1.5);
+    }
+}
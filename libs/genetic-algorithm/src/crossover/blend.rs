@@ -0,0 +1,125 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// BLX-α: picks each gene uniformly from the interval spanned by the
+/// parents' genes, extended on both sides by `alpha` times its length -
+/// so that children can explore a bit beyond their parents.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlendCrossover {
+    /// How far outside of the parents' interval a gene can land:
+    /// - 0.0 = genes stay between parents' genes
+    /// - 0.5 = genes can land up to half the interval's length outside
+    alpha: f32,
+}
+
+impl BlendCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!(alpha >= 0.0);
+
+        Self { alpha }
+    }
+}
+
+impl CrossoverMethod for BlendCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome
+    {
+        use rand::Rng;
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let extent = self.alpha * (a - b).abs();
+                let min = a.min(b) - extent;
+                let max = a.max(b) + extent;
+
+                if min < max {
+                    rng.gen_range(min..max)
+                } else {
+                    a
+                }
+            })
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::Blend(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (1..=100)
+            .map(|n| n as f32)
+            .collect();
+
+        let parent_b = (1..=100)
+            .map(|n| -n as f32)
+            .collect();
+
+        (parent_a, parent_b)
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = BlendCrossover::new(0.5)
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        // Gene `n` lies within -n..n, extended by half of its length
+        // (i.e. `n`) on each side
+        assert!(child
+            .iter()
+            .enumerate()
+            .all(|(idx, gene)| gene.abs() <= 2.0 * (idx + 1) as f32));
+
+        let beyond = child
+            .iter()
+            .enumerate()
+            .filter(|(idx, gene)| gene.abs() > (idx + 1) as f32)
+            .count();
+
+        assert_eq!(beyond, 40);
+    }
+
+    #[test]
+    fn given_zero_alpha_stays_between_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = BlendCrossover::new(0.0)
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        assert!(child
+            .iter()
+            .enumerate()
+            .all(|(idx, gene)| gene.abs() <= (idx + 1) as f32));
+    }
+
+    #[test]
+    fn given_identical_parents_copies_them() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+
+        let child = BlendCrossover::new(0.5)
+            .crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// Cuts both parents at the same `k` random points and builds the child
+/// out of alternating segments - starting with `parent_a`'s - keeping
+/// neighbouring genes together.
+///
+/// Chromosomes of length `n` can be cut at most `n - 1` times; excess
+/// cuts are ignored.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KPointCrossover {
+    k: usize,
+}
+
+impl KPointCrossover {
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0);
+
+        Self { k }
+    }
+}
+
+impl<G> CrossoverMethod<G> for KPointCrossover
+where
+    G: Clone,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>
+    {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let len = parent_a.len();
+        let k = self.k.min(len.saturating_sub(1));
+
+        // A cut at point `n` means genes `n..` come from the other parent
+        let mut points: Vec<_> = rand::seq::index::sample(rng, len.saturating_sub(1), k)
+            .into_iter()
+            .map(|point| point + 1)
+            .collect();
+
+        points.sort_unstable();
+
+        let mut points = points.into_iter().peekable();
+        let mut from_a = true;
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .enumerate()
+            .map(|(idx, (a, b))| {
+                while points.next_if(|&point| point == idx).is_some() {
+                    from_a = !from_a;
+                }
+
+                if from_a { a.clone() } else { b.clone() }
+            })
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::KPoint(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (1..=100)
+            .map(|n| n as f32)
+            .collect();
+
+        let parent_b = (1..=100)
+            .map(|n| -n as f32)
+            .collect();
+
+        (parent_a, parent_b)
+    }
+
+    /// Returns indices at which the child switches between parents.
+    fn switches(child: &Chromosome) -> Vec<usize> {
+        (1..child.len())
+            .filter(|&idx| child[idx].signum() != child[idx - 1].signum())
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = KPointCrossover::new(3)
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        // Genes are never moved around, just taken from either parent
+        assert!(child
+            .iter()
+            .enumerate()
+            .all(|(idx, gene)| gene.abs() == (idx + 1) as f32));
+
+        assert!(child[0] > 0.0);
+        assert_eq!(switches(&child), vec![19, 63, 83]);
+    }
+
+    #[test]
+    fn caps_number_of_cuts() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+        let parent_b: Chromosome = vec![-1.0, -2.0, -3.0].into_iter().collect();
+
+        let child = KPointCrossover::new(10)
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        let genes: Vec<_> = child.into_iter().collect();
+
+        assert_eq!(genes, vec![1.0, -2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn crossover_with_different_lengths_panics() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0].into_iter().collect();
+        let parent_b: Chromosome = vec![1.0].into_iter().collect();
+
+        KPointCrossover::new(1).crossover(&mut rng, &parent_a, &parent_b);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// Order crossover (OX), meant for permutations: copies a random segment
+/// of `parent_a` as it is, then fills the remaining positions - starting
+/// right after the segment and wrapping around - with the missing genes,
+/// in the order they appear in `parent_b`.
+///
+/// Both parents have to be permutations of the same genes.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderCrossover;
+
+impl OrderCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for OrderCrossover
+where
+    G: Clone + PartialEq,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>
+    {
+        use rand::Rng;
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let len = parent_a.len();
+        let mut cuts = [rng.gen_range(0..=len), rng.gen_range(0..=len)];
+        cuts.sort_unstable();
+
+        let [start, end] = cuts;
+        let segment = &parent_a.iter().collect::<Vec<_>>()[start..end];
+
+        let mut rest = (0..len)
+            .map(|idx| &parent_b[(end + idx) % len])
+            .filter(|gene| !segment.contains(gene));
+
+        let mut child: Vec<Option<G>> = vec![None; len];
+
+        for (idx, gene) in segment.iter().enumerate() {
+            child[start + idx] = Some((*gene).clone());
+        }
+
+        for idx in (0..len).map(|idx| (end + idx) % len) {
+            if child[idx].is_none() {
+                child[idx] = rest.next().cloned();
+            }
+        }
+
+        child
+            .into_iter()
+            .map(|gene| gene.expect("parents aren't permutations of the same genes"))
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::Order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn child(parent_a: &[usize], parent_b: &[usize]) -> Vec<usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome<usize> = parent_a.iter().cloned().collect();
+        let parent_b: Chromosome<usize> = parent_b.iter().cloned().collect();
+
+        OrderCrossover::new()
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let child = child(
+            &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[9, 3, 7, 8, 2, 6, 5, 1, 4, 0],
+        );
+
+        assert_eq!(child, vec![9, 1, 2, 3, 4, 5, 6, 7, 8, 0]);
+    }
+
+    #[test]
+    fn yields_permutations() {
+        use rand::seq::SliceRandom;
+
+        for seed in 0..50 {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+            for len in 0..20 {
+                let mut parent_a: Vec<usize> = (0..len).collect();
+                let mut parent_b = parent_a.clone();
+
+                parent_a.shuffle(&mut rng);
+                parent_b.shuffle(&mut rng);
+
+                let parent_a: Chromosome<usize> = parent_a.into_iter().collect();
+                let parent_b: Chromosome<usize> = parent_b.into_iter().collect();
+
+                let mut genes: Vec<_> = OrderCrossover::new()
+                    .crossover(&mut rng, &parent_a, &parent_b)
+                    .into_iter()
+                    .collect();
+
+                genes.sort_unstable();
+
+                assert_eq!(genes, (0..len).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn crossover_with_different_lengths_panics() {
+        child(&[0, 1, 2, 3], &[0, 1, 2]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// Partially mapped crossover (PMX), meant for permutations: copies a
+/// random segment of `parent_a` as it is and takes the rest from
+/// `parent_b`, resolving duplicates through the mapping between both
+/// parents' segments - so that most genes keep their absolute positions.
+///
+/// Both parents have to be permutations of the same genes.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PmxCrossover;
+
+impl PmxCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for PmxCrossover
+where
+    G: Clone + PartialEq,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>
+    {
+        use rand::Rng;
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let len = parent_a.len();
+        let mut cuts = [rng.gen_range(0..=len), rng.gen_range(0..=len)];
+        cuts.sort_unstable();
+
+        let [start, end] = cuts;
+        let segment = start..end;
+
+        let position_in_b = |gene: &G| {
+            parent_b
+                .iter()
+                .position(|other| other == gene)
+                .expect("parents aren't permutations of the same genes")
+        };
+
+        let mut child: Vec<Option<G>> = vec![None; len];
+
+        for idx in segment.clone() {
+            child[idx] = Some(parent_a[idx].clone());
+        }
+
+        // Genes from `parent_b`'s segment that didn't make it into the
+        // child get moved to where `parent_a`'s genes are in `parent_b`
+        for idx in segment.clone() {
+            let gene = &parent_b[idx];
+
+            if parent_a.iter().skip(start).take(end - start).any(|other| other == gene) {
+                continue;
+            }
+
+            let mut target = idx;
+
+            while segment.contains(&target) {
+                target = position_in_b(&parent_a[target]);
+            }
+
+            child[target] = Some(gene.clone());
+        }
+
+        child
+            .into_iter()
+            .enumerate()
+            .map(|(idx, gene)| gene.unwrap_or_else(|| parent_b[idx].clone()))
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::Pmx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn child(parent_a: &[usize], parent_b: &[usize]) -> Vec<usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome<usize> = parent_a.iter().cloned().collect();
+        let parent_b: Chromosome<usize> = parent_b.iter().cloned().collect();
+
+        PmxCrossover::new()
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let child = child(
+            &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[9, 3, 7, 8, 2, 6, 5, 1, 4, 0],
+        );
+
+        assert_eq!(child, vec![9, 1, 2, 3, 4, 5, 6, 7, 8, 0]);
+    }
+
+    #[test]
+    fn yields_permutations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome<usize> = (0..20).collect();
+        let parent_b: Chromosome<usize> = (0..20).rev().collect();
+
+        for _ in 0..100 {
+            let mut genes: Vec<_> = PmxCrossover::new()
+                .crossover(&mut rng, &parent_a, &parent_b)
+                .into_iter()
+                .collect();
+
+            genes.sort_unstable();
+
+            assert_eq!(genes, (0..20).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters},
+};
+
+/// SBX: mimics single-point crossover of binary-encoded genes by
+/// spreading children around their parents, with a spread drawn from a
+/// polynomial distribution - children close to their parents are the
+/// most likely ones.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulatedBinaryCrossover {
+    /// Distribution index (η):
+    /// - small values (e.g. 2.0) = children can land far from parents
+    /// - large values (e.g. 20.0) = children stay close to parents
+    eta: f32,
+
+    /// Optional `(min, max)` range of each gene; out-of-range genes get
+    /// clamped back into it.
+    bounds: Option<Vec<(f32, f32)>>,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(eta: f32) -> Self {
+        assert!(eta >= 0.0);
+
+        Self { eta, bounds: None }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f32, f32)>) -> Self {
+        assert!(bounds.iter().all(|(min, max)| min <= max));
+
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl CrossoverMethod for SimulatedBinaryCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome
+    {
+        use rand::Rng;
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        if let Some(bounds) = &self.bounds {
+            assert_eq!(bounds.len(), parent_a.len());
+        }
+
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .enumerate()
+            .map(|(idx, (&a, &b))| {
+                let u: f32 = rng.gen();
+
+                let beta = if u <= 0.5 {
+                    (2.0 * u).powf(exponent)
+                } else {
+                    (1.0 / (2.0 * (1.0 - u))).powf(exponent)
+                };
+
+                // SBX yields two symmetrical children; we pick one of
+                // them at random
+                let sign = if rng.gen_bool(0.5) { -1.0 } else { 1.0 };
+                let gene = 0.5 * ((a + b) + sign * beta * (a - b));
+
+                match &self.bounds {
+                    Some(bounds) => gene.clamp(bounds[idx].0, bounds[idx].1),
+                    None => gene,
+                }
+            })
+            .collect()
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::SimulatedBinary(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (0..100)
+            .map(|_| 1.0)
+            .collect();
+
+        let parent_b = (0..100)
+            .map(|_| -1.0)
+            .collect();
+
+        (parent_a, parent_b)
+    }
+
+    fn spread(eta: f32) -> f32 {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = SimulatedBinaryCrossover::new(eta)
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        child.iter().map(|gene| gene.abs()).sum::<f32>() / child.len() as f32
+    }
+
+    #[test]
+    fn test() {
+        approx::assert_relative_eq!(spread(2.0), 1.0830468);
+    }
+
+    #[test]
+    fn larger_eta_keeps_children_closer_to_parents() {
+        // Parents sit at ±1.0, so the closer to 1.0, the closer to parents
+        assert!((spread(20.0) - 1.0).abs() < (spread(2.0) - 1.0).abs());
+    }
+
+    #[test]
+    fn given_identical_parents_copies_them() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+
+        let child = SimulatedBinaryCrossover::new(2.0)
+            .crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+
+    #[test]
+    fn respects_bounds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = SimulatedBinaryCrossover::new(0.0)
+            .with_bounds(vec![(-0.5, 0.5); 100])
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        assert!(child.iter().all(|gene| (-0.5..=0.5).contains(gene)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn crossover_with_mismatched_bounds_panics() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        SimulatedBinaryCrossover::new(2.0)
+            .with_bounds(vec![(-0.5, 0.5); 3])
+            .crossover(&mut rng, &parent_a, &parent_b);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, CrossoverParameters, KPointCrossover},
+};
+
+/// Cuts both parents at the same random point and glues `parent_a`'s
+/// head to `parent_b`'s tail.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SinglePointCrossover;
+
+impl SinglePointCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for SinglePointCrossover
+where
+    G: Clone,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>
+    {
+        KPointCrossover::new(1).crossover(rng, parent_a, parent_b)
+    }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::SinglePoint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome =
+            (1..=100)
+                .map(|n| n as f32)
+                .collect();
+        let parent_b: Chromosome =
+            (1..=100)
+                .map(|n| -n as f32)
+                .collect();
+
+        let child = SinglePointCrossover::new()
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        let head = child
+            .iter()
+            .take_while(|gene| **gene > 0.0)
+            .count();
+
+        let tail = child
+            .iter()
+            .skip(head)
+            .filter(|gene| **gene < 0.0)
+            .count();
+
+        assert_eq!(head, 19);
+        assert_eq!(head + tail, 100);
+    }
+}
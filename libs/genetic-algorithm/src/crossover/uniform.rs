@@ -1,9 +1,10 @@
 use crate::{
     chromosome::Chromosome,
-    crossover::CrossoverMethod,
+    crossover::{CrossoverMethod, CrossoverParameters},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCrossover;
 
 impl UniformCrossover {
@@ -12,13 +13,16 @@ impl UniformCrossover {
     }
 }
 
-impl CrossoverMethod for UniformCrossover {
+impl<G> CrossoverMethod<G> for UniformCrossover
+where
+    G: Clone,
+{
     fn crossover(
         &self,
         rng: &mut dyn rand::RngCore,
-        parent_a: &Chromosome,
-        parent_b: &Chromosome,
-    ) -> Chromosome
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>
     {
         use rand::Rng;
         assert_eq!(parent_a.len(), parent_b.len());
@@ -26,9 +30,13 @@ impl CrossoverMethod for UniformCrossover {
         parent_a
             .iter()
             .zip(parent_b.iter())
-            .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
+            .map(|(a, b)| if rng.gen_bool(0.5) { a.clone() } else { b.clone() })
             .collect()
     }
+
+    fn parameters(&self) -> CrossoverParameters {
+        CrossoverParameters::Uniform
+    }
 }
 
 #[cfg(test)]
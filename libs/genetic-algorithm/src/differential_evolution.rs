@@ -0,0 +1,323 @@
+use crate::{
+    chromosome::Chromosome,
+    evaluation::{evaluate, Evaluated, MaybeSync},
+    individual::Individual,
+    optimizer::Optimizer,
+    statistics::Statistics,
+};
+
+/// Describes how [`DifferentialEvolution`] builds mutant vectors, which
+/// then get crossed over (binomially) with their targets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeVariant {
+    /// DE/rand/1/bin: `x_r1 + F * (x_r2 - x_r3)` - explores the most.
+    Rand1Bin,
+
+    /// DE/best/1/bin: `x_best + F * (x_r1 - x_r2)` - converges the
+    /// fastest, at the risk of getting stuck.
+    Best1Bin,
+
+    /// DE/current-to-best/1/bin:
+    /// `x_i + F * (x_best - x_i) + F * (x_r1 - x_r2)` - somewhere in
+    /// between.
+    CurrentToBest1Bin,
+}
+
+/// Differential evolution: perturbs each individual (target) with scaled
+/// differences between other individuals, and lets the resulting trial
+/// replace its target only if it's at least as fit.
+///
+/// Since trials have to be evaluated before they can be compared with
+/// their targets, the targets are kept inside the optimizer: the first
+/// population passed to `evolve()` becomes the targets, and afterwards
+/// `evolve()` expects to be given back the trials it returned the last
+/// time (with fitness filled in, in the same order).
+#[derive(Clone, Debug)]
+pub struct DifferentialEvolution {
+    variant: DeVariant,
+
+    /// Differential weight (F), scaling the difference vectors.
+    f: f32,
+
+    /// Crossover probability (CR), i.e. chance of taking a gene from the
+    /// mutant instead of the target.
+    cr: f32,
+
+    /// Current targets, along with their fitness.
+    targets: Vec<(f32, Chromosome)>,
+}
+
+impl DifferentialEvolution {
+    pub fn new(variant: DeVariant, f: f32, cr: f32) -> Self {
+        assert!(f > 0.0 && f <= 2.0);
+        assert!((0.0..=1.0).contains(&cr));
+
+        Self {
+            variant,
+            f,
+            cr,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Fittest target found so far, if there are any targets yet.
+    pub fn best(&self) -> Option<&Chromosome> {
+        self.best_idx().map(|idx| &self.targets[idx].1)
+    }
+
+    fn best_idx(&self) -> Option<usize> {
+        (0..self.targets.len()).max_by(|&a, &b| {
+            self.targets[a]
+                .0
+                .partial_cmp(&self.targets[b].0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
+
+    /// Builds the trial for the `idx`-th target.
+    fn trial(&self, rng: &mut dyn rand::RngCore, idx: usize, best: usize) -> Chromosome {
+        use rand::Rng;
+
+        let others: Vec<_> = rand::seq::index::sample(rng, self.targets.len() - 1, 3)
+            .into_iter()
+            .map(|other| if other >= idx { other + 1 } else { other })
+            .collect();
+
+        let gene = |target: usize, gene: usize| self.targets[target].1[gene];
+
+        let len = self.targets[idx].1.len();
+        let forced = rng.gen_range(0..len.max(1));
+
+        (0..len)
+            .map(|j| {
+                // Binomial crossover, with at least one gene (`forced`)
+                // always taken from the mutant
+                if j != forced && !rng.gen_bool(self.cr as _) {
+                    return gene(idx, j);
+                }
+
+                let (r1, r2, r3) = (others[0], others[1], others[2]);
+
+                match self.variant {
+                    DeVariant::Rand1Bin => {
+                        gene(r1, j) + self.f * (gene(r2, j) - gene(r3, j))
+                    }
+
+                    DeVariant::Best1Bin => {
+                        gene(best, j) + self.f * (gene(r1, j) - gene(r2, j))
+                    }
+
+                    DeVariant::CurrentToBest1Bin => {
+                        gene(idx, j)
+                            + self.f * (gene(best, j) - gene(idx, j))
+                            + self.f * (gene(r1, j) - gene(r2, j))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl<I> Optimizer<I> for DifferentialEvolution
+where
+    I: Individual<Gene = f32> + MaybeSync,
+{
+    fn evolve(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: &[I],
+    ) -> (Vec<I>, Statistics)
+    {
+        assert!(population.len() >= 4, "differential evolution needs at least 4 individuals");
+
+        let population = evaluate(population);
+        let statistics = Statistics::new(population.iter().map(Evaluated::fitness));
+
+        if self.targets.is_empty() {
+            self.targets = population
+                .iter()
+                .map(|target| (target.fitness(), target.individual().chromosome().clone()))
+                .collect();
+        } else {
+            assert_eq!(population.len(), self.targets.len());
+
+            for (target, trial) in self.targets.iter_mut().zip(&population) {
+                if trial.fitness() >= target.0 {
+                    *target = (trial.fitness(), trial.individual().chromosome().clone());
+                }
+            }
+        }
+
+        let best = self.best_idx().unwrap();
+
+        let trials = (0..self.targets.len())
+            .map(|idx| I::create(self.trial(rng, idx, best)))
+            .collect();
+
+        (trials, statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crossover::UniformCrossover,
+        mutation::GaussianMutation,
+        replacement::Replacement,
+        selection::TournamentSelection,
+        GeneticAlgorithm,
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// Fitness = -(Rastrigin function), whose optimum lies at zero,
+    /// surrounded by plenty of local optima.
+    #[derive(Debug)]
+    struct Rastrigin(Chromosome);
+
+    impl Individual for Rastrigin {
+        type Gene = f32;
+
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+
+        fn fitness(&self) -> f32 {
+            use std::f32::consts::PI;
+
+            -self
+                .0
+                .iter()
+                .map(|x| 10.0 + x * x - 10.0 * (2.0 * PI * x).cos())
+                .sum::<f32>()
+        }
+    }
+
+    /// Fitness = -(ellipsoid function), whose single optimum lies at
+    /// zero, but with each gene scaled differently.
+    #[derive(Debug)]
+    struct Ellipsoid(Chromosome);
+
+    impl Individual for Ellipsoid {
+        type Gene = f32;
+
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+
+        fn fitness(&self) -> f32 {
+            -self
+                .0
+                .iter()
+                .enumerate()
+                .map(|(idx, x)| 10f32.powi(idx as i32) * x * x)
+                .sum::<f32>()
+        }
+    }
+
+    fn population<I>(rng: &mut dyn rand::RngCore) -> Vec<I>
+    where
+        I: Individual<Gene = f32>,
+    {
+        (0..30)
+            .map(|_| I::create((0..5).map(|_| rng.gen_range(-5.12..5.12)).collect()))
+            .collect()
+    }
+
+    /// Returns the best fitness found within `generations`.
+    fn run<I>(optimizer: &mut dyn Optimizer<I>, generations: usize) -> f32
+    where
+        I: Individual<Gene = f32>,
+    {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut population = population(&mut rng);
+        let mut best = f32::NEG_INFINITY;
+
+        for _ in 0..generations {
+            let (new_population, statistics) = optimizer.evolve(&mut rng, &population);
+
+            best = best.max(statistics.max_fitness());
+            population = new_population;
+        }
+
+        best
+    }
+
+    fn de(variant: DeVariant) -> DifferentialEvolution {
+        DifferentialEvolution::new(variant, 0.5, 0.9)
+    }
+
+    fn ga() -> GeneticAlgorithm<TournamentSelection> {
+        GeneticAlgorithm::new(
+            TournamentSelection::new(2),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.2, 0.5),
+        )
+        .with_replacement(Replacement::Generational { elitism: 1 })
+    }
+
+    #[test]
+    fn never_loses_the_best_target() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut de = de(DeVariant::Rand1Bin);
+        let mut population: Vec<Rastrigin> = population(&mut rng);
+        let mut best = f32::NEG_INFINITY;
+
+        for _ in 0..20 {
+            population = de.evolve(&mut rng, &population).0;
+
+            let target = Rastrigin::create(de.best().unwrap().clone()).fitness();
+
+            assert!(target >= best);
+            best = target;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn evolve_with_too_small_population_panics() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population: Vec<Rastrigin> = population(&mut rng).into_iter().take(3).collect();
+
+        de(DeVariant::Rand1Bin).evolve(&mut rng, &population);
+    }
+
+    mod compared_to_uniform_crossover_with_gaussian_mutation {
+        use super::*;
+
+        #[test]
+        fn does_better_on_ellipsoid() {
+            let ga = run::<Ellipsoid>(&mut ga(), 200);
+
+            // DE/current-to-best/1/bin is left out, since it tends to
+            // collapse before it gets to tune the steepest gene
+            for &variant in &[DeVariant::Rand1Bin, DeVariant::Best1Bin] {
+                let de = run::<Ellipsoid>(&mut de(variant), 200);
+
+                assert!(de > ga, "{:?} reached {}, while GA reached {}", variant, de, ga);
+            }
+        }
+
+        #[test]
+        fn does_better_on_rastrigin() {
+            let ga = run::<Rastrigin>(&mut ga(), 200);
+
+            // DE/best/1/bin is left out, since it tends to get stuck in one
+            // of Rastrigin's local optima
+            for &variant in &[DeVariant::Rand1Bin, DeVariant::CurrentToBest1Bin] {
+                let de = run::<Rastrigin>(&mut de(variant), 200);
+
+                assert!(de > ga, "{:?} reached {}, while GA reached {}", variant, de, ga);
+            }
+        }
+    }
+}
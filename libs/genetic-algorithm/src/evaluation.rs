@@ -0,0 +1,150 @@
+use crate::{
+    individual::{Fitness, Individual, MultiObjectiveIndividual},
+};
+
+/// `Sync` when the `parallel` feature is enabled (since individuals are
+/// then evaluated across threads), no-op otherwise.
+#[cfg(feature = "parallel")]
+pub trait MaybeSync: Sync {}
+
+#[cfg(feature = "parallel")]
+impl<T> MaybeSync for T where T: Sync {}
+
+/// `Sync` when the `parallel` feature is enabled (since individuals are
+/// then evaluated across threads), no-op otherwise.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T {}
+
+/// Individual with its fitness computed up-front, so that selection
+/// methods and statistics - which tend to ask for it over and over - don't
+/// have to recompute it.
+pub(crate) struct Evaluated<'a, I> {
+    individual: &'a I,
+    fitness: f32,
+}
+
+impl<'a, I> Evaluated<'a, I> {
+    pub(crate) fn individual(&self) -> &'a I {
+        self.individual
+    }
+
+    pub(crate) fn fitness(&self) -> f32 {
+        self.fitness
+    }
+}
+
+impl<I> Fitness for Evaluated<'_, I> {
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+}
+
+/// Computes fitness of each individual exactly once - in parallel, if
+/// the `parallel` feature is enabled.
+///
+/// Fitness functions don't get access to the random number generator, so
+/// the order of evaluation doesn't affect the results.
+pub(crate) fn evaluate<I>(population: &[I]) -> Vec<Evaluated<'_, I>>
+where
+    I: Individual + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    let fitnesses: Vec<f32> = {
+        use rayon::prelude::*;
+
+        population
+            .par_iter()
+            .map(|individual| individual.fitness())
+            .collect()
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    let fitnesses: Vec<f32> = population
+        .iter()
+        .map(|individual| individual.fitness())
+        .collect();
+
+    population
+        .iter()
+        .zip(fitnesses)
+        .map(|(individual, fitness)| Evaluated { individual, fitness })
+        .collect()
+}
+
+/// Computes objectives of each individual exactly once - in parallel, if
+/// the `parallel` feature is enabled.
+pub(crate) fn evaluate_objectives<I>(population: &[I]) -> Vec<Vec<f32>>
+where
+    I: MultiObjectiveIndividual + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        population
+            .par_iter()
+            .map(|individual| individual.objectives())
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        population
+            .iter()
+            .map(|individual| individual.objectives())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chromosome::Chromosome;
+    use std::cell::Cell;
+
+    thread_local! {
+        static EVALUATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct CountingIndividual(Chromosome);
+
+    impl Individual for CountingIndividual {
+        type Gene = f32;
+
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+
+        fn fitness(&self) -> f32 {
+            EVALUATIONS.with(|evaluations| evaluations.set(evaluations.get() + 1));
+            self.0.iter().sum()
+        }
+    }
+
+    #[test]
+    fn evaluates_each_individual_once() {
+        let population: Vec<_> = (0..10)
+            .map(|n| CountingIndividual::create(vec![n as f32].into_iter().collect()))
+            .collect();
+
+        let evaluated = evaluate(&population);
+
+        for _ in 0..3 {
+            for (n, individual) in evaluated.iter().enumerate() {
+                assert_eq!(individual.fitness(), n as f32);
+            }
+        }
+
+        // (with the `parallel` feature, evaluations happen on other threads)
+        if cfg!(not(feature = "parallel")) {
+            assert_eq!(EVALUATIONS.with(Cell::get), 10);
+        }
+    }
+}
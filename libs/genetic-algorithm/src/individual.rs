@@ -1,11 +1,47 @@
 use crate::{chromosome::Chromosome};
 
 pub trait Individual {
-    fn create(chromosome: Chromosome) -> Self;
-    fn chromosome(&self) -> &Chromosome;
+    /// Type of the genes making up this individual's chromosome.
+    type Gene;
+
+    fn create(chromosome: Chromosome<Self::Gene>) -> Self;
+    fn chromosome(&self) -> &Chromosome<Self::Gene>;
+    fn fitness(&self) -> f32;
+}
+
+/// Anything that has a fitness score - which is all that selection
+/// methods need to know about the individuals they pick from.
+///
+/// It's implemented for every [`Individual`] already, so there's no need
+/// to implement it by hand.
+pub trait Fitness {
     fn fitness(&self) -> f32;
 }
 
+impl<I> Fitness for I
+where
+    I: Individual,
+{
+    fn fitness(&self) -> f32 {
+        Individual::fitness(self)
+    }
+}
+
+/// Individual judged by several objectives at once, instead of a single
+/// fitness; see [`Nsga2`](crate::nsga2::Nsga2).
+pub trait MultiObjectiveIndividual {
+    /// Type of the genes making up this individual's chromosome.
+    type Gene;
+
+    fn create(chromosome: Chromosome<Self::Gene>) -> Self;
+    fn chromosome(&self) -> &Chromosome<Self::Gene>;
+
+    /// Values of all the objectives, each one to be maximized (negate the
+    /// ones that should be minimized); every individual has to return the
+    /// same number of them.
+    fn objectives(&self) -> Vec<f32>;
+}
+
 #[cfg(test)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum TestIndividual {
@@ -22,6 +58,8 @@ impl TestIndividual {
 
 #[cfg(test)]
 impl Individual for TestIndividual {
+    type Gene = f32;
+
     fn create(chromosome: Chromosome) -> Self {
         Self::WithChromosome { chromosome }
     }
@@ -0,0 +1,330 @@
+use crate::{
+    evaluation::{evaluate, MaybeSync},
+    individual::Individual,
+    selection::SelectionMethod,
+    statistics::Statistics,
+    GeneticAlgorithm,
+};
+
+/// Describes which islands send their migrants where.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MigrationTopology {
+    /// Island `n` sends migrants to island `n + 1`, with the last one
+    /// sending them back to the first one.
+    Ring,
+
+    /// Every island sends migrants to every other island.
+    FullyConnected,
+
+    /// Every island sends migrants to another island picked at random,
+    /// anew each migration.
+    Random,
+}
+
+/// Evolves several sub-populations (islands) side by side, each with its
+/// own [`GeneticAlgorithm`] - so islands can use different operators and
+/// parameters, as long as they share the selection method's type - and
+/// every `interval` generations sends copies of each island's `migrants`
+/// fittest individuals to other islands, where they replace the least
+/// fit ones.
+///
+/// Since islands mostly evolve in isolation, they tend to explore
+/// different parts of the search space, which keeps the overall
+/// population from converging prematurely.
+pub struct IslandModel<S, G = f32> {
+    islands: Vec<GeneticAlgorithm<S, G>>,
+    topology: MigrationTopology,
+    interval: usize,
+    migrants: usize,
+    generation: usize,
+}
+
+impl<S, G> IslandModel<S, G>
+where
+    S: SelectionMethod,
+    G: Clone,
+{
+    pub fn new(
+        islands: Vec<GeneticAlgorithm<S, G>>,
+        topology: MigrationTopology,
+        interval: usize,
+        migrants: usize,
+    ) -> Self {
+        assert!(!islands.is_empty());
+        assert!(interval > 0);
+
+        Self {
+            islands,
+            topology,
+            interval,
+            migrants,
+            generation: 0,
+        }
+    }
+
+    pub fn islands(&self) -> &[GeneticAlgorithm<S, G>] {
+        &self.islands
+    }
+
+    /// Index of the generation that's going to be evolved next.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Evolves each island's population (`populations[n]` belongs to the
+    /// `n`-th island) by one generation, migrating individuals afterwards
+    /// if it's the time to; returns new populations along with statistics
+    /// of each island's (parent) population.
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        populations: &[Vec<I>],
+    ) -> (Vec<Vec<I>>, Vec<Statistics>)
+    where
+        I: Individual<Gene = G> + MaybeSync,
+    {
+        assert_eq!(populations.len(), self.islands.len());
+
+        let (mut new_populations, statistics): (Vec<_>, Vec<_>) = self
+            .islands
+            .iter_mut()
+            .zip(populations)
+            .map(|(island, population)| island.evolve(rng, population))
+            .unzip();
+
+        self.generation += 1;
+
+        if self.generation % self.interval == 0 {
+            migrate(rng, &mut new_populations, self.topology, self.migrants);
+        }
+
+        (new_populations, statistics)
+    }
+}
+
+/// Sends copies of each population's `migrants` fittest individuals over
+/// to populations chosen by `topology`, replacing their least fit ones.
+fn migrate<I>(
+    rng: &mut dyn rand::RngCore,
+    populations: &mut [Vec<I>],
+    topology: MigrationTopology,
+    migrants: usize,
+) where
+    I: Individual + MaybeSync,
+    I::Gene: Clone,
+{
+    use rand::Rng;
+
+    let count = populations.len();
+
+    if count < 2 || migrants == 0 {
+        return;
+    }
+
+    // Emigrants are picked before anybody arrives, so that migrants don't
+    // travel more than one island per migration
+    let emigrants: Vec<Vec<I>> = populations
+        .iter()
+        .map(|population| {
+            let population = evaluate(population);
+
+            let fittest = population
+                .iter()
+                .map(|individual| (individual.fitness(), individual.individual()));
+
+            crate::fittest(fittest, migrants)
+                .into_iter()
+                .map(crate::clone)
+                .collect()
+        })
+        .collect();
+
+    let mut immigrants: Vec<Vec<I>> = (0..count).map(|_| Vec::new()).collect();
+
+    for (source, emigrants) in emigrants.into_iter().enumerate() {
+        let targets: Vec<usize> = match topology {
+            MigrationTopology::Ring => vec![(source + 1) % count],
+
+            MigrationTopology::FullyConnected => {
+                (0..count).filter(|&target| target != source).collect()
+            }
+
+            MigrationTopology::Random => {
+                // Picks any island but the source one
+                let target = rng.gen_range(0..count - 1);
+                vec![if target >= source { target + 1 } else { target }]
+            }
+        };
+
+        for target in targets {
+            immigrants[target].extend(emigrants.iter().map(crate::clone));
+        }
+    }
+
+    for (population, immigrants) in populations.iter_mut().zip(immigrants) {
+        let fitnesses: Vec<_> = evaluate(population)
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
+
+        let mut least_fit: Vec<usize> = (0..population.len()).collect();
+
+        least_fit.sort_by(|&a, &b| {
+            fitnesses[a]
+                .partial_cmp(&fitnesses[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for (idx, immigrant) in least_fit.into_iter().zip(immigrants) {
+            population[idx] = immigrant;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crossover::UniformCrossover,
+        individual::TestIndividual,
+        mutation::GaussianMutation,
+        selection::RouletteWheelSelection,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn fitnesses(population: &[TestIndividual]) -> Vec<f32> {
+        population.iter().map(|individual| individual.fitness()).collect()
+    }
+
+    /// Island `n` consists of individuals with fitness `10n`, `10n + 1`
+    /// and `10n + 2`.
+    fn populations(count: usize) -> Vec<Vec<TestIndividual>> {
+        (0..count)
+            .map(|n| {
+                (0..3)
+                    .map(|m| individual(&[(10 * n + m) as f32]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    mod migrate {
+        use super::*;
+
+        fn actual(topology: MigrationTopology, migrants: usize) -> Vec<Vec<f32>> {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut populations = populations(3);
+
+            migrate(&mut rng, &mut populations, topology, migrants);
+
+            populations.iter().map(|population| fitnesses(population)).collect()
+        }
+
+        #[test]
+        fn ring() {
+            assert_eq!(
+                actual(MigrationTopology::Ring, 1),
+                vec![
+                    vec![22.0, 1.0, 2.0],
+                    vec![2.0, 11.0, 12.0],
+                    vec![12.0, 21.0, 22.0],
+                ],
+            );
+        }
+
+        #[test]
+        fn fully_connected() {
+            assert_eq!(
+                actual(MigrationTopology::FullyConnected, 1),
+                vec![
+                    vec![12.0, 22.0, 2.0],
+                    vec![2.0, 22.0, 12.0],
+                    vec![2.0, 12.0, 22.0],
+                ],
+            );
+        }
+
+        #[test]
+        fn random() {
+            // Islands #1 and #2 both happened to pick island #0, which has
+            // room for only three out of their four migrants
+            assert_eq!(
+                actual(MigrationTopology::Random, 2),
+                vec![
+                    vec![12.0, 11.0, 22.0],
+                    vec![10.0, 11.0, 12.0],
+                    vec![2.0, 1.0, 22.0],
+                ],
+            );
+        }
+
+        #[test]
+        fn given_zero_migrants_does_nothing() {
+            assert_eq!(
+                actual(MigrationTopology::Ring, 0),
+                populations(3).iter().map(|population| fitnesses(population)).collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    fn model(interval: usize) -> IslandModel<RouletteWheelSelection> {
+        let islands = (0..3)
+            .map(|_| {
+                GeneticAlgorithm::new(
+                    RouletteWheelSelection::new(),
+                    UniformCrossover::new(),
+                    GaussianMutation::new(0.0, 0.0),
+                )
+            })
+            .collect();
+
+        IslandModel::new(islands, MigrationTopology::Ring, interval, 1)
+    }
+
+    #[test]
+    fn migrates_every_interval() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(2);
+        let mut populations = populations(3);
+
+        let has_foreigners = |populations: &[Vec<TestIndividual>]| {
+            populations.iter().enumerate().any(|(n, population)| {
+                population
+                    .iter()
+                    .any(|individual| (individual.fitness() as usize) / 10 != n)
+            })
+        };
+
+        populations = model.evolve(&mut rng, &populations).0;
+        assert!(!has_foreigners(&populations));
+
+        populations = model.evolve(&mut rng, &populations).0;
+        assert!(has_foreigners(&populations));
+
+        assert_eq!(model.generation(), 2);
+    }
+
+    #[test]
+    fn reports_statistics_of_each_island() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(1);
+
+        let (_, statistics) = model.evolve(&mut rng, &populations(3));
+
+        assert_eq!(statistics.len(), 3);
+        approx::assert_relative_eq!(statistics[2].max_fitness(), 22.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn evolve_with_wrong_number_of_populations_panics() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        model(1).evolve(&mut rng, &populations(2));
+    }
+}
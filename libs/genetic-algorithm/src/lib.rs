@@ -1,64 +1,155 @@
 pub mod individual;
 pub mod selection;
+pub mod checkpoint;
 pub mod chromosome;
+pub mod cma_es;
 pub mod crossover;
+pub mod differential_evolution;
+pub mod evaluation;
+pub mod island;
 pub mod mutation;
+pub mod nsga2;
+pub mod optimizer;
+pub mod replacement;
+pub mod statistics;
+pub mod termination;
 
 use crate::{
     crossover::CrossoverMethod,
+    evaluation::{evaluate, Evaluated, MaybeSync},
     individual::Individual,
     mutation::MutationMethod,
+    replacement::Replacement,
     selection::SelectionMethod,
+    statistics::Statistics,
 };
 
-pub struct GeneticAlgorithm<S> {
+/// Evolves populations of individuals whose chromosomes consist of genes
+/// of type `G` (real numbers by default).
+pub struct GeneticAlgorithm<S, G = f32> {
     selection_method: S,
-    crossover_method: Box<dyn CrossoverMethod>,
-    mutation_method: Box<dyn MutationMethod>,
+    crossover_method: Box<dyn CrossoverMethod<G>>,
+    mutation_method: Box<dyn MutationMethod<G>>,
+    replacement: Replacement,
+    generation: usize,
 }
 
-impl<S> GeneticAlgorithm<S>
+impl<S, G> GeneticAlgorithm<S, G>
 where
-    S: SelectionMethod
+    S: SelectionMethod,
+    G: Clone,
 {
     pub fn new(
         selection_method: S,
-        crossover_method: impl CrossoverMethod + 'static,
-        mutation_method: impl MutationMethod + 'static,
+        crossover_method: impl CrossoverMethod<G> + 'static,
+        mutation_method: impl MutationMethod<G> + 'static,
     ) -> Self {
         Self {
             selection_method,
             crossover_method: Box::new(crossover_method),
             mutation_method: Box::new(mutation_method),
+            replacement: Replacement::default(),
+            generation: 0,
         }
     }
 
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Index of the generation that's going to be evolved next, i.e. how
+    /// many times `evolve()` has been called so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Breeds a new generation out of `population`, returning it along
+    /// with statistics of the (parent) `population`.
+    ///
+    /// Before breeding, the mutation method gets a chance to adjust
+    /// itself to those statistics (see [`MutationMethod::update()`]).
+    ///
+    /// Each individual's fitness is computed exactly once per call (in
+    /// parallel, if the `parallel` feature is enabled).
     pub fn evolve<I>(
-        &self,
+        &mut self,
         rng: &mut dyn rand::RngCore,
         population: &[I]
-    ) -> Vec<I>
+    ) -> (Vec<I>, Statistics)
     where
-        I: Individual,
+        I: Individual<Gene = G> + MaybeSync,
     {
         assert!(!population.is_empty());
 
-        (0..population.len())
-            .map(|_| {
-                let parent_a = self
-                    .selection_method
-                    .select(rng, population)
-                    .chromosome();
+        let population = evaluate(population);
+        let statistics = Statistics::new(population.iter().map(Evaluated::fitness));
+
+        self.mutation_method.update(self.generation, &statistics);
+        self.generation += 1;
+
+        let new_population = match self.replacement {
+            Replacement::Generational { elitism } => {
+                assert!(elitism <= population.len());
+
+                let elites = population
+                    .iter()
+                    .map(|parent| (parent.fitness(), parent.individual()));
+
+                let elites = fittest(elites, elitism);
+                let offspring = self.breed(rng, &population, population.len() - elitism);
+
+                elites.into_iter().map(clone).chain(offspring).collect()
+            }
+
+            Replacement::Plus { offspring } => {
+                let parents = population
+                    .iter()
+                    .map(|parent| (parent.fitness(), clone(parent.individual())));
+
+                let offspring = self.breed(rng, &population, offspring);
+                let offspring = scored(offspring);
 
-                let parent_b = self
-                    .selection_method
-                    .select(rng, population)
-                    .chromosome();
+                fittest(parents.chain(offspring), population.len())
+            }
+
+            Replacement::Comma { offspring } => {
+                assert!(offspring >= population.len());
+
+                let offspring = self.breed(rng, &population, offspring);
+
+                fittest(scored(offspring), population.len())
+            }
+        };
+
+        (new_population, statistics)
+    }
+
+    /// Creates `count` children out of parents picked from `population`.
+    fn breed<I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &[Evaluated<'_, I>],
+        count: usize,
+    ) -> Vec<I>
+    where
+        I: Individual<Gene = G>,
+    {
+        let parents = self
+            .selection_method
+            .select_many(rng, population, 2 * count);
+
+        parents
+            .chunks(2)
+            .map(|parents| {
+                let parent_a = parents[0].individual().chromosome();
+                let parent_b = parents[1].individual().chromosome();
 
                 let mut child = self
                     .crossover_method
                     .crossover(rng, parent_a, parent_b);
 
+                child.inherit_strategy(parent_a, parent_b);
                 self.mutation_method.mutate(rng, &mut child);
                 I::create(child)
             })
@@ -66,14 +157,52 @@ where
     }
 }
 
+/// Re-creates an individual out of its chromosome; note that the
+/// fitness of the copy doesn't have to match the original one's.
+fn clone<I>(individual: &I) -> I
+where
+    I: Individual,
+    I::Gene: Clone,
+{
+    I::create(individual.chromosome().clone())
+}
+
+/// Pairs individuals with their fitness, computing each one only once.
+fn scored<I>(individuals: Vec<I>) -> impl Iterator<Item = (f32, I)>
+where
+    I: Individual + MaybeSync,
+{
+    let fitnesses: Vec<_> = evaluate(&individuals)
+        .iter()
+        .map(|individual| individual.fitness())
+        .collect();
+
+    fitnesses.into_iter().zip(individuals)
+}
+
+/// Returns (at most) `count` fittest individuals, fittest first.
+fn fittest<T>(individuals: impl IntoIterator<Item = (f32, T)>, count: usize) -> Vec<T> {
+    let mut individuals: Vec<_> = individuals.into_iter().collect();
+
+    individuals.sort_by(|(a, _), (b, _)| {
+        b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    individuals
+        .into_iter()
+        .take(count)
+        .map(|(_, individual)| individual)
+        .collect()
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::SeedableRng;
+    use rand::{seq::SliceRandom, Rng, SeedableRng};
     use rand_chacha::ChaCha8Rng;
     use crate::{
         individual::TestIndividual,
+        replacement::Replacement,
         selection::RouletteWheelSelection,
         mutation::GaussianMutation,
         crossover::UniformCrossover,
@@ -90,7 +219,7 @@ mod tests {
     fn evolve_with_empty_population_panics() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
         let population = Vec::<TestIndividual>::default();
-        let algo = GeneticAlgorithm::new(
+        let mut algo = GeneticAlgorithm::new(
             RouletteWheelSelection,
             UniformCrossover,
             GaussianMutation::new(0.5, 0.5),
@@ -103,7 +232,7 @@ mod tests {
     fn test() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-        let ga = GeneticAlgorithm::new(
+        let mut ga = GeneticAlgorithm::new(
             RouletteWheelSelection::new(),
             UniformCrossover::new(),
             GaussianMutation::new(0.5, 0.5),
@@ -117,17 +246,489 @@ mod tests {
         ];
 
         for _ in 0..10 {
-            population = ga.evolve(&mut rng, &population);
+            population = ga.evolve(&mut rng, &population).0;
         }
 
         let expected_population = vec![
-            individual(&[1.6119734, 1.8159671, 0.31497368]),
-            individual(&[1.0151604, 1.1331394, 0.8526902]),
-            individual(&[2.1268358, 2.932069, 0.10471791]),
-            individual(&[0.77124745, 1.1331394, 0.9507327]),
+            individual(&[0.69659567, 1.4682187, 5.144134]),
+            individual(&[1.014194, 1.6389127, 4.283209]),
+            individual(&[0.8888707, 1.7969121, 4.284353]),
+            individual(&[0.69659567, 1.0751513, 4.5697117]),
         ];
 
         assert_eq!(population, expected_population);
     }
 
+    #[test]
+    fn evolve_counts_generations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            crate::mutation::ScheduledMutation::new(
+                GaussianMutation::new(0.5, 0.5),
+                crate::mutation::MutationSchedule::ExponentialDecay { rate: 0.5 },
+            ),
+        );
+
+        let mut population = vec![
+            individual(&[1.0, 1.0, 1.0]),
+            individual(&[1.0, 2.0, 4.0]),
+        ];
+
+        assert_eq!(ga.generation(), 0);
+
+        for _ in 0..3 {
+            population = ga.evolve(&mut rng, &population).0;
+        }
+
+        assert_eq!(ga.generation(), 3);
+    }
+
+    mod run {
+        use super::*;
+        use crate::{
+            optimizer::Optimizer,
+            termination::{StopCondition, StopReason},
+        };
+
+        fn ga() -> GeneticAlgorithm<RouletteWheelSelection> {
+            GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMutation::new(0.5, 0.5),
+            )
+            .with_replacement(Replacement::Generational { elitism: 1 })
+        }
+
+        fn population() -> Vec<TestIndividual> {
+            vec![
+                individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+                individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+                individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+                individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+            ]
+        }
+
+        #[test]
+        fn stops_after_max_generations() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let outcome = ga().run(&mut rng, population(), &StopCondition::MaxGenerations(5));
+
+            assert_eq!(outcome.generations(), 5);
+            assert_eq!(outcome.history().len(), 5);
+            assert_eq!(outcome.population().len(), 4);
+            assert_eq!(outcome.stop_reasons(), &[StopReason::MaxGenerations]);
+        }
+
+        #[test]
+        fn stops_once_target_fitness_is_reached() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let stop = StopCondition::Any(vec![
+                StopCondition::TargetFitness(10.0),
+                StopCondition::MaxGenerations(1000),
+            ]);
+
+            let outcome = ga().run(&mut rng, population(), &stop);
+
+            assert!(outcome.generations() < 1000);
+            assert_eq!(outcome.stop_reasons(), &[StopReason::TargetFitness]);
+            assert!(outcome.history().last().unwrap().max_fitness() >= 10.0);
+
+            // Returned population has been bred out of the one that has
+            // reached the target, with the fittest individual kept as-is
+            let statistics = Statistics::new(outcome.population().iter().map(Individual::fitness));
+
+            assert!(statistics.max_fitness() >= 10.0);
+        }
+
+        #[test]
+        fn with_zero_max_generations_returns_initial_population() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let outcome = ga().run(&mut rng, population(), &StopCondition::MaxGenerations(0));
+
+            assert_eq!(outcome.population(), population().as_slice());
+            assert_eq!(outcome.generations(), 0);
+            assert_eq!(outcome.stop_reasons(), &[StopReason::MaxGenerations]);
+        }
+
+        #[test]
+        fn with_zero_plateau_generations_stops_after_first_one() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let stop = StopCondition::Plateau {
+                generations: 0,
+                tolerance: 0.0,
+            };
+
+            let outcome = ga().run(&mut rng, population(), &stop);
+
+            assert_eq!(outcome.generations(), 1);
+            assert_eq!(outcome.stop_reasons(), &[StopReason::Plateau]);
+
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            assert_eq!(outcome.into_population(), ga().evolve(&mut rng, &population()).0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn with_empty_any_panics() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            ga().run(&mut rng, population(), &StopCondition::Any(vec![]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn with_nested_empty_all_panics() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let stop = StopCondition::Any(vec![
+                StopCondition::TargetFitness(1000.0),
+                StopCondition::All(vec![]),
+            ]);
+
+            ga().run(&mut rng, population(), &stop);
+        }
+
+        #[test]
+        fn matches_evolving_by_hand() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga();
+            let mut population = population();
+
+            for _ in 0..10 {
+                population = ga.evolve(&mut rng, &population).0;
+            }
+
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let outcome = self::ga().run(
+                &mut rng,
+                self::population(),
+                &StopCondition::MaxGenerations(10),
+            );
+
+            assert_eq!(outcome.into_population(), population);
+        }
+    }
+
+    #[test]
+    fn evolve_with_batch_selection() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            crate::selection::StochasticUniversalSampling::new(),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.0, 0.0),
+        );
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+            individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        let (new_population, _) = ga.evolve(&mut rng, &population);
+
+        // The unfit individual never gets picked, so none of its genes
+        // could've been passed down
+        assert_eq!(new_population.len(), 4);
+        assert!(new_population.iter().all(|individual| individual.chromosome()[0] == 1.0));
+    }
+
+    mod replacement {
+        use super::*;
+
+        fn ga(replacement: Replacement) -> GeneticAlgorithm<RouletteWheelSelection> {
+            GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMutation::new(1.0, 2.0),
+            )
+            .with_replacement(replacement)
+        }
+
+        fn population() -> Vec<TestIndividual> {
+            vec![
+                individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+                individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+                individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+                individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+            ]
+        }
+
+        #[test]
+        fn generational_carries_over_the_elite() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Generational { elitism: 2 });
+
+            let (new_population, _) = ga.evolve(&mut rng, &population());
+
+            assert_eq!(new_population.len(), 4);
+            assert_eq!(new_population[0], individual(&[1.0, 2.0, 4.0]));
+            assert_eq!(new_population[1], individual(&[1.0, 2.0, 1.0]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn generational_with_too_large_elite_panics() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Generational { elitism: 5 });
+
+            ga.evolve(&mut rng, &population());
+        }
+
+        #[test]
+        fn generational_with_elitism_never_regresses() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Generational { elitism: 1 });
+            let mut population = population();
+
+            for _ in 0..20 {
+                let (new_population, statistics) = ga.evolve(&mut rng, &population);
+                let new_statistics =
+                    Statistics::new(new_population.iter().map(Individual::fitness));
+
+                assert!(new_statistics.max_fitness() >= statistics.max_fitness());
+
+                population = new_population;
+            }
+        }
+
+        #[test]
+        fn plus_never_regresses() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Plus { offspring: 6 });
+            let mut population = population();
+
+            for _ in 0..20 {
+                let (new_population, statistics) = ga.evolve(&mut rng, &population);
+                let new_statistics =
+                    Statistics::new(new_population.iter().map(Individual::fitness));
+
+                assert_eq!(new_population.len(), 4);
+                assert!(new_statistics.min_fitness() >= statistics.min_fitness());
+                assert!(new_statistics.max_fitness() >= statistics.max_fitness());
+
+                population = new_population;
+            }
+        }
+
+        #[test]
+        fn comma_keeps_the_fittest_offspring() {
+            // Breeds the very same offspring `evolve()` is going to breed
+            // (mutation doesn't consume any randomness in `update()`)
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population = population();
+
+            let mut offspring: Vec<_> = ga(Replacement::Comma { offspring: 8 })
+                .breed(&mut rng, &evaluate(&population), 8)
+                .into_iter()
+                .map(|child| (child.fitness(), child))
+                .collect();
+
+            offspring.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap());
+
+            let expected: Vec<_> = offspring
+                .into_iter()
+                .take(4)
+                .map(|(_, child)| child)
+                .collect();
+
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Comma { offspring: 8 });
+
+            let (new_population, _) = ga.evolve(&mut rng, &population);
+
+            assert_eq!(new_population, expected);
+
+            for individual in &new_population {
+                assert!(!population.contains(individual));
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn comma_with_too_few_offspring_panics() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Replacement::Comma { offspring: 3 });
+
+            ga.evolve(&mut rng, &population());
+        }
+    }
+
+    #[test]
+    fn evolve_passes_strategy_down_to_children() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.0, 0.0),
+        );
+
+        let population: Vec<_> = [1.0, 3.0]
+            .iter()
+            .map(|&sigma| {
+                let mut chromosome: crate::chromosome::Chromosome =
+                    vec![1.0, 1.0, 1.0].into_iter().collect();
+
+                chromosome.set_strategy(vec![sigma]);
+                TestIndividual::create(chromosome)
+            })
+            .collect();
+
+        let (new_population, _) = ga.evolve(&mut rng, &population);
+
+        assert!(new_population.iter().all(|individual| {
+            let strategy = individual.chromosome().strategy();
+
+            [[1.0], [2.0], [3.0]].iter().any(|expected| strategy == expected)
+        }));
+    }
+
+    mod gene_types {
+        use super::*;
+        use crate::{
+            chromosome::Chromosome,
+            crossover::OrderCrossover,
+            mutation::{BitFlipMutation, SwapMutation},
+            selection::TournamentSelection,
+        };
+
+        /// Fitness = number of set bits ("OneMax").
+        struct BitString(Chromosome<bool>);
+
+        impl Individual for BitString {
+            type Gene = bool;
+
+            fn create(chromosome: Chromosome<bool>) -> Self {
+                Self(chromosome)
+            }
+
+            fn chromosome(&self) -> &Chromosome<bool> {
+                &self.0
+            }
+
+            fn fitness(&self) -> f32 {
+                self.0.iter().filter(|bit| **bit).count() as f32
+            }
+        }
+
+        /// Fitness = number of neighbouring genes that are in order.
+        struct Permutation(Chromosome<usize>);
+
+        impl Individual for Permutation {
+            type Gene = usize;
+
+            fn create(chromosome: Chromosome<usize>) -> Self {
+                Self(chromosome)
+            }
+
+            fn chromosome(&self) -> &Chromosome<usize> {
+                &self.0
+            }
+
+            fn fitness(&self) -> f32 {
+                let genes: Vec<_> = self.0.iter().collect();
+
+                genes.windows(2).filter(|pair| pair[0] < pair[1]).count() as f32
+            }
+        }
+
+        #[test]
+        fn evolves_bit_strings() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let mut ga = GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                UniformCrossover::new(),
+                BitFlipMutation::new(0.05),
+            )
+            .with_replacement(Replacement::Generational { elitism: 1 });
+
+            let mut population: Vec<_> = (0..20)
+                .map(|_| BitString::create((0..20).map(|_| rng.gen_bool(0.5)).collect()))
+                .collect();
+
+            let initial = Statistics::new(population.iter().map(Individual::fitness));
+
+            for _ in 0..30 {
+                population = ga.evolve(&mut rng, &population).0;
+            }
+
+            let evolved = Statistics::new(population.iter().map(Individual::fitness));
+
+            assert!(evolved.max_fitness() > initial.max_fitness());
+        }
+
+        #[test]
+        fn evolves_permutations() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let mut ga = GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                OrderCrossover::new(),
+                SwapMutation::new(0.5),
+            )
+            .with_replacement(Replacement::Generational { elitism: 1 });
+
+            let mut population: Vec<_> = (0..20)
+                .map(|_| {
+                    let mut genes: Vec<usize> = (0..10).collect();
+                    genes.shuffle(&mut rng);
+
+                    Permutation::create(genes.into_iter().collect())
+                })
+                .collect();
+
+            let initial = Statistics::new(population.iter().map(Individual::fitness));
+
+            for _ in 0..100 {
+                population = ga.evolve(&mut rng, &population).0;
+            }
+
+            let evolved = Statistics::new(population.iter().map(Individual::fitness));
+
+            assert!(evolved.max_fitness() > initial.max_fitness());
+
+            assert!(population.iter().all(|individual| {
+                let mut genes: Vec<_> = individual.chromosome().iter().cloned().collect();
+                genes.sort_unstable();
+                genes == (0..10).collect::<Vec<_>>()
+            }));
+        }
+    }
+
+    #[test]
+    fn evolve_reports_statistics_of_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.5, 0.5),
+        );
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+            individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        let (_, statistics) = ga.evolve(&mut rng, &population);
+
+        approx::assert_relative_eq!(statistics.min_fitness(), 0.0);
+        approx::assert_relative_eq!(statistics.max_fitness(), 7.0);
+        approx::assert_relative_eq!(statistics.mean_fitness(), 3.5);
+        approx::assert_relative_eq!(statistics.median_fitness(), 3.5);
+    }
 }
@@ -1,15 +1,70 @@
+mod bit_flip;
+mod cauchy;
 mod gaussian;
+mod inversion;
+mod normal;
+mod polynomial;
+mod scheduled;
+mod self_adaptive;
+mod swap;
 
-pub use self::gaussian::*;
+pub use self::{
+    bit_flip::*,
+    cauchy::*,
+    gaussian::*,
+    inversion::*,
+    normal::*,
+    polynomial::*,
+    scheduled::*,
+    self_adaptive::*,
+    swap::*,
+};
 
 use crate::{
     chromosome::Chromosome,
+    statistics::Statistics,
 };
 
-pub trait MutationMethod {
+pub trait MutationMethod<G = f32> {
     fn mutate(
         &self,
         rng: &mut dyn rand::RngCore,
-        child: &mut Chromosome,
+        child: &mut Chromosome<G>,
     );
+
+    /// Called by [`GeneticAlgorithm`](crate::GeneticAlgorithm) once per
+    /// generation, before any breeding happens, with statistics of the
+    /// population that's about to be bred; methods that adjust
+    /// themselves over the run (see [`ScheduledMutation`]) override it,
+    /// while the rest simply ignore it.
+    fn update(&mut self, _generation: usize, _statistics: &Statistics) {}
+
+    /// Settings this method has been created with - i.e. without the
+    /// state it builds up through `update()`, which gets replayed when a
+    /// run is resumed.
+    fn parameters(&self) -> MutationParameters;
+}
+
+/// Parameters of a [`MutationMethod`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MutationParameters {
+    BitFlip(BitFlipMutation),
+    Cauchy(CauchyMutation),
+    Gaussian(GaussianMutation),
+    Inversion(InversionMutation),
+    Normal(NormalMutation),
+    Polynomial(PolynomialMutation),
+
+    Scheduled {
+        initial: GaussianMutation,
+        schedule: MutationSchedule,
+    },
+
+    SelfAdaptive(SelfAdaptiveMutation),
+    Swap(SwapMutation),
+
+    /// Method defined outside of this crate; the string is up to it, as
+    /// long as it stays the same across builds for the same settings.
+    Custom(String),
 }
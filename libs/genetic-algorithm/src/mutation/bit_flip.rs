@@ -0,0 +1,73 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+
+/// Flips bits of binary chromosomes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitFlipMutation {
+    /// Probability of flipping a bit:
+    /// - 0.0 = no bits will be touched
+    /// - 1.0 = all bits will be touched
+    chance: f32,
+}
+
+impl BitFlipMutation {
+    pub fn new(chance: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+
+        Self { chance }
+    }
+}
+
+impl MutationMethod<bool> for BitFlipMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome<bool>) {
+        use rand::Rng;
+
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene = !*gene;
+            }
+        }
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::BitFlip(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<bool> {
+        let mut child = vec![false, false, true, true, false]
+            .into_iter()
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        BitFlipMutation::new(chance)
+            .mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        assert_eq!(actual(0.0), vec![false, false, true, true, false]);
+    }
+
+    #[test]
+    fn given_fifty_fifty_chance_flips_some_bits() {
+        assert_eq!(actual(0.5), vec![false, false, false, false, true]);
+    }
+
+    #[test]
+    fn given_max_chance_flips_all_bits() {
+        assert_eq!(actual(1.0), vec![true, true, false, false, true]);
+    }
+}
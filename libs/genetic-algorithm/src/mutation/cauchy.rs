@@ -0,0 +1,98 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+use rand_distr::{Cauchy, Distribution};
+
+/// Perturbs genes with values drawn from the Cauchy distribution; its
+/// heavy tails make occasional large jumps way more likely than with
+/// [`NormalMutation`](crate::mutation::NormalMutation), which helps to
+/// escape local optima.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CauchyMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Scale (half width at half maximum) of that change.
+    scale: f32,
+}
+
+impl CauchyMutation {
+    pub fn new(chance: f32, scale: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(scale > 0.0);
+
+        Self { chance, scale }
+    }
+}
+
+impl MutationMethod for CauchyMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome) {
+        use rand::Rng;
+
+        let cauchy = Cauchy::new(0.0, self.scale).unwrap();
+
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += cauchy.sample(rng);
+            }
+        }
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Cauchy(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, scale: f32) -> Vec<f32> {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        CauchyMutation::new(chance, scale)
+            .mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(0.0, 0.5);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_max_chance_changes_the_original_chromosome() {
+        let actual = actual(1.0, 0.5);
+        let expected = vec![1.3335003, 1.718788, 2.8531392, 2.8586097, 4.5361714];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn perturbations_have_the_given_median_deviation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child: Chromosome = (0..10_000).map(|_| 0.0).collect();
+
+        CauchyMutation::new(1.0, 2.0).mutate(&mut rng, &mut child);
+
+        // Cauchy has no mean nor variance, but half of its samples land
+        // within `scale` of the center
+        let within = child.iter().filter(|gene| gene.abs() <= 2.0).count();
+
+        approx::assert_abs_diff_eq!(within as f32 / child.len() as f32, 0.5, epsilon = 0.02);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
     chromosome::Chromosome,
-    mutation::MutationMethod,
+    mutation::{MutationMethod, MutationParameters},
 };
 
-#[derive(Clone, Debug)]
+/// Perturbs genes by up to `coeff` in either direction.
+///
+/// Despite the name, the perturbation is drawn from a uniform
+/// distribution; see [`NormalMutation`](crate::mutation::NormalMutation)
+/// for an actually normal-distributed one.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GaussianMutation {
     /// Probability of changing a gene:
     /// - 0.0 = no genes will be touched
@@ -18,10 +24,18 @@ pub struct GaussianMutation {
 
 impl GaussianMutation {
     pub fn new(chance: f32, coeff: f32) -> Self {
-        assert!(chance >= 0.0 && chance <= 1.0);
+        assert!((0.0..=1.0).contains(&chance));
 
         Self { chance, coeff }
     }
+
+    pub fn chance(&self) -> f32 {
+        self.chance
+    }
+
+    pub fn coeff(&self) -> f32 {
+        self.coeff
+    }
 }
 
 impl MutationMethod for GaussianMutation {
@@ -35,6 +49,10 @@ impl MutationMethod for GaussianMutation {
             }
         }
     }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Gaussian(self.clone())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,70 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+
+/// Reverses order of genes within a random segment, keeping
+/// permutations valid.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InversionMutation {
+    /// Probability of mutating a chromosome (not a gene):
+    /// - 0.0 = no chromosomes will be touched
+    /// - 1.0 = all chromosomes will be touched
+    chance: f32,
+}
+
+impl InversionMutation {
+    pub fn new(chance: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+
+        Self { chance }
+    }
+}
+
+impl<G> MutationMethod<G> for InversionMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome<G>) {
+        use rand::Rng;
+
+        if !rng.gen_bool(self.chance as _) {
+            return;
+        }
+
+        let len = child.len();
+        let mut cuts = [rng.gen_range(0..=len), rng.gen_range(0..=len)];
+        cuts.sort_unstable();
+
+        child.reverse(cuts[0]..cuts[1]);
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Inversion(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<usize> {
+        let mut child = (0..10).collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        InversionMutation::new(chance)
+            .mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        assert_eq!(actual(0.0), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn given_max_chance_reverses_a_segment() {
+        assert_eq!(actual(1.0), vec![0, 1, 8, 7, 6, 5, 4, 3, 2, 9]);
+    }
+}
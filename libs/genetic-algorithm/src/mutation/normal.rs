@@ -0,0 +1,106 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+use rand_distr::{Distribution, Normal};
+
+/// Perturbs genes with values drawn from the normal distribution
+/// N(0, σ) - small changes are the most common ones, but larger ones
+/// are still possible.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NormalMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Standard deviation (σ) of that change.
+    sigma: f32,
+}
+
+impl NormalMutation {
+    pub fn new(chance: f32, sigma: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(sigma >= 0.0);
+
+        Self { chance, sigma }
+    }
+}
+
+impl MutationMethod for NormalMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome) {
+        use rand::Rng;
+
+        let normal = Normal::new(0.0, self.sigma).unwrap();
+
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += normal.sample(rng);
+            }
+        }
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Normal(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, sigma: f32) -> Vec<f32> {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        NormalMutation::new(chance, sigma)
+            .mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(0.0, 0.5);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_zero_sigma_does_not_change_the_original_chromosome() {
+        let actual = actual(1.0, 0.0);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_max_chance_changes_the_original_chromosome() {
+        let actual = actual(1.0, 0.5);
+        let expected = vec![1.6888486, 2.2026734, 2.4018655, 3.0324764, 4.664113];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn perturbations_follow_the_normal_distribution() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child: Chromosome = (0..10_000).map(|_| 0.0).collect();
+
+        NormalMutation::new(1.0, 2.0).mutate(&mut rng, &mut child);
+
+        let n = child.len() as f32;
+        let mean = child.iter().sum::<f32>() / n;
+        let std_dev = (child.iter().map(|gene| (gene - mean).powi(2)).sum::<f32>() / n).sqrt();
+
+        approx::assert_abs_diff_eq!(mean, 0.0, epsilon = 0.05);
+        approx::assert_abs_diff_eq!(std_dev, 2.0, epsilon = 0.05);
+    }
+}
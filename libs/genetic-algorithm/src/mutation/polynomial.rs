@@ -0,0 +1,143 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+
+/// Deb's polynomial mutation: perturbs genes with values drawn from a
+/// polynomial distribution centered at zero, whose shape is controlled by
+/// the distribution index (η).
+///
+/// With bounds set, perturbations are scaled by each gene's range and
+/// mutated genes are clamped back into it; without them, perturbations
+/// stay within -1.0..1.0.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolynomialMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Distribution index (η):
+    /// - small values (e.g. 5.0) = larger changes are common
+    /// - large values (e.g. 100.0) = changes are mostly tiny
+    eta: f32,
+
+    /// Optional `(min, max)` range of each gene.
+    bounds: Option<Vec<(f32, f32)>>,
+}
+
+impl PolynomialMutation {
+    pub fn new(chance: f32, eta: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(eta >= 0.0);
+
+        Self { chance, eta, bounds: None }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f32, f32)>) -> Self {
+        assert!(bounds.iter().all(|(min, max)| min <= max));
+
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl MutationMethod for PolynomialMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome) {
+        use rand::Rng;
+
+        if let Some(bounds) = &self.bounds {
+            assert_eq!(bounds.len(), child.len());
+        }
+
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        for (idx, gene) in child.iter_mut().enumerate() {
+            if !rng.gen_bool(self.chance as _) {
+                continue;
+            }
+
+            let u: f32 = rng.gen();
+
+            let delta = if u < 0.5 {
+                (2.0 * u).powf(exponent) - 1.0
+            } else {
+                1.0 - (2.0 * (1.0 - u)).powf(exponent)
+            };
+
+            match &self.bounds {
+                Some(bounds) => {
+                    let (min, max) = bounds[idx];
+                    *gene = (*gene + delta * (max - min)).clamp(min, max);
+                }
+
+                None => {
+                    *gene += delta;
+                }
+            }
+        }
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Polynomial(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(mutation: PolynomialMutation) -> Vec<f32> {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        mutation.mutate(&mut rng, &mut child);
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(PolynomialMutation::new(0.0, 20.0));
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_max_chance_changes_the_original_chromosome() {
+        let actual = actual(PolynomialMutation::new(1.0, 20.0));
+        let expected = vec![0.9543049, 2.0519526, 3.0779574, 4.014417, 5.0347204];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn respects_bounds() {
+        let actual = actual(
+            PolynomialMutation::new(1.0, 0.0)
+                .with_bounds(vec![(0.0, 6.0); 5])
+        );
+
+        assert!(actual.iter().all(|gene| (0.0..=6.0).contains(gene)));
+    }
+
+    #[test]
+    fn larger_eta_yields_smaller_changes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut change = |eta| {
+            let mut child: Chromosome = (0..1000).map(|_| 0.0).collect();
+
+            PolynomialMutation::new(1.0, eta).mutate(&mut rng, &mut child);
+            child.iter().map(|gene| gene.abs()).sum::<f32>()
+        };
+
+        assert!(change(100.0) < change(5.0));
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{GaussianMutation, MutationMethod, MutationParameters},
+    statistics::Statistics,
+};
+
+/// How [`ScheduledMutation`] varies its chance and coefficient over the
+/// run.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MutationSchedule {
+    /// Linearly moves from the initial chance and coefficient to the
+    /// final ones over `generations`, keeping the final ones afterwards.
+    LinearDecay {
+        final_chance: f32,
+        final_coeff: f32,
+        generations: usize,
+    },
+
+    /// Multiplies both the initial chance and coefficient by `rate` each
+    /// generation (i.e. they're scaled by `rate ^ generation`).
+    ExponentialDecay {
+        rate: f32,
+    },
+
+    /// Rechenberg's 1/5th success rule: every `window` generations, if
+    /// more than a fifth of them improved the population's mean fitness,
+    /// the coefficient gets divided by `factor` (exploring further away);
+    /// if fewer did, it gets multiplied by `factor` (exploiting closer).
+    ///
+    /// `factor` should be within 0.0..1.0 - Schwefel suggests 0.82.
+    OneFifthRule {
+        window: usize,
+        factor: f32,
+    },
+
+    /// Multiplies both the initial chance and coefficient by `factor`
+    /// once the fitness' standard deviation has stayed below `threshold`
+    /// for `patience` generations in a row (i.e. once the population has
+    /// stagnated), going back to the initial ones as soon as the
+    /// diversity recovers.
+    DiversityBoost {
+        threshold: f32,
+        patience: usize,
+        factor: f32,
+    },
+}
+
+/// [`GaussianMutation`] whose chance and coefficient change over the run
+/// according to a [`MutationSchedule`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduledMutation {
+    initial: GaussianMutation,
+    current: GaussianMutation,
+    schedule: MutationSchedule,
+
+    /// Mean fitness of the previous generation (for the 1/5th rule).
+    last_mean_fitness: Option<f32>,
+
+    /// How many generations have improved within the current window
+    /// (for the 1/5th rule).
+    successes: usize,
+
+    /// How many generations the current window has seen so far (for the
+    /// 1/5th rule) or how many generations in a row have stagnated (for
+    /// the diversity boost).
+    streak: usize,
+}
+
+impl ScheduledMutation {
+    pub fn new(initial: GaussianMutation, schedule: MutationSchedule) -> Self {
+        match &schedule {
+            MutationSchedule::LinearDecay { final_chance, .. } => {
+                assert!((0.0..=1.0).contains(final_chance));
+            }
+
+            MutationSchedule::ExponentialDecay { rate } => {
+                assert!(*rate > 0.0);
+            }
+
+            MutationSchedule::OneFifthRule { window, factor } => {
+                assert!(*window > 0);
+                assert!(*factor > 0.0 && *factor < 1.0);
+            }
+
+            MutationSchedule::DiversityBoost { factor, .. } => {
+                assert!(*factor > 0.0);
+            }
+        }
+
+        Self {
+            current: initial.clone(),
+            initial,
+            schedule,
+            last_mean_fitness: None,
+            successes: 0,
+            streak: 0,
+        }
+    }
+
+    /// Mutation that's going to be applied to the next children.
+    pub fn current(&self) -> &GaussianMutation {
+        &self.current
+    }
+
+    fn scaled(&self, chance_factor: f32, coeff_factor: f32) -> GaussianMutation {
+        GaussianMutation::new(
+            (self.initial.chance() * chance_factor).min(1.0),
+            self.initial.coeff() * coeff_factor,
+        )
+    }
+}
+
+impl MutationMethod for ScheduledMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome) {
+        self.current.mutate(rng, child);
+    }
+
+    fn update(&mut self, generation: usize, statistics: &Statistics) {
+        match self.schedule {
+            MutationSchedule::LinearDecay { final_chance, final_coeff, generations } => {
+                let t = if generations == 0 {
+                    1.0
+                } else {
+                    (generation as f32 / generations as f32).min(1.0)
+                };
+
+                let chance = self.initial.chance() + t * (final_chance - self.initial.chance());
+                let coeff = self.initial.coeff() + t * (final_coeff - self.initial.coeff());
+
+                self.current = GaussianMutation::new(chance, coeff);
+            }
+
+            MutationSchedule::ExponentialDecay { rate } => {
+                let factor = rate.powi(generation as i32);
+
+                self.current = self.scaled(factor, factor);
+            }
+
+            MutationSchedule::OneFifthRule { window, factor } => {
+                let mean_fitness = statistics.mean_fitness();
+
+                if let Some(last_mean_fitness) = self.last_mean_fitness.replace(mean_fitness) {
+                    if mean_fitness > last_mean_fitness {
+                        self.successes += 1;
+                    }
+
+                    self.streak += 1;
+                }
+
+                if self.streak == window {
+                    let success_rate = self.successes as f32 / window as f32;
+
+                    let coeff = if success_rate > 0.2 {
+                        self.current.coeff() / factor
+                    } else if success_rate < 0.2 {
+                        self.current.coeff() * factor
+                    } else {
+                        self.current.coeff()
+                    };
+
+                    self.current = GaussianMutation::new(self.current.chance(), coeff);
+                    self.successes = 0;
+                    self.streak = 0;
+                }
+            }
+
+            MutationSchedule::DiversityBoost { threshold, patience, factor } => {
+                if statistics.std_dev_fitness() < threshold {
+                    self.streak += 1;
+                } else {
+                    self.streak = 0;
+                }
+
+                self.current = if self.streak >= patience {
+                    self.scaled(factor, factor)
+                } else {
+                    self.initial.clone()
+                };
+            }
+        }
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Scheduled {
+            initial: self.initial.clone(),
+            schedule: self.schedule.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics(fitnesses: &[f32]) -> Statistics {
+        Statistics::new(fitnesses.iter().copied())
+    }
+
+    fn mutation(schedule: MutationSchedule) -> ScheduledMutation {
+        ScheduledMutation::new(GaussianMutation::new(0.5, 2.0), schedule)
+    }
+
+    fn assert_current(mutation: &ScheduledMutation, chance: f32, coeff: f32) {
+        approx::assert_relative_eq!(mutation.current().chance(), chance);
+        approx::assert_relative_eq!(mutation.current().coeff(), coeff);
+    }
+
+    #[test]
+    fn linear_decay() {
+        let mut mutation = mutation(MutationSchedule::LinearDecay {
+            final_chance: 0.1,
+            final_coeff: 1.0,
+            generations: 4,
+        });
+
+        let statistics = statistics(&[1.0, 2.0]);
+
+        mutation.update(0, &statistics);
+        assert_current(&mutation, 0.5, 2.0);
+
+        mutation.update(2, &statistics);
+        assert_current(&mutation, 0.3, 1.5);
+
+        mutation.update(4, &statistics);
+        assert_current(&mutation, 0.1, 1.0);
+
+        mutation.update(100, &statistics);
+        assert_current(&mutation, 0.1, 1.0);
+    }
+
+    #[test]
+    fn exponential_decay() {
+        let mut mutation = mutation(MutationSchedule::ExponentialDecay { rate: 0.5 });
+        let statistics = statistics(&[1.0, 2.0]);
+
+        mutation.update(0, &statistics);
+        assert_current(&mutation, 0.5, 2.0);
+
+        mutation.update(2, &statistics);
+        assert_current(&mutation, 0.125, 0.5);
+    }
+
+    mod one_fifth_rule {
+        use super::*;
+
+        fn run(mean_fitnesses: &[f32]) -> ScheduledMutation {
+            let mut mutation = mutation(MutationSchedule::OneFifthRule {
+                window: 5,
+                factor: 0.5,
+            });
+
+            for (generation, &mean_fitness) in mean_fitnesses.iter().enumerate() {
+                mutation.update(generation, &statistics(&[mean_fitness]));
+            }
+
+            mutation
+        }
+
+        #[test]
+        fn given_frequent_successes_widens_the_search() {
+            let mutation = run(&[1.0, 2.0, 3.0, 3.0, 3.0, 3.0]);
+
+            assert_current(&mutation, 0.5, 4.0);
+        }
+
+        #[test]
+        fn given_rare_successes_narrows_the_search() {
+            let mutation = run(&[1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+
+            assert_current(&mutation, 0.5, 1.0);
+        }
+
+        #[test]
+        fn given_exactly_one_fifth_of_successes_keeps_the_search() {
+            let mutation = run(&[1.0, 2.0, 2.0, 2.0, 2.0, 2.0]);
+
+            assert_current(&mutation, 0.5, 2.0);
+        }
+
+        #[test]
+        fn waits_for_the_window_to_fill_up() {
+            let mutation = run(&[1.0, 1.0, 1.0, 1.0, 1.0]);
+
+            assert_current(&mutation, 0.5, 2.0);
+        }
+    }
+
+    #[test]
+    fn diversity_boost() {
+        let mut mutation = mutation(MutationSchedule::DiversityBoost {
+            threshold: 0.1,
+            patience: 2,
+            factor: 3.0,
+        });
+
+        let diverse = statistics(&[1.0, 2.0]);
+        let stagnant = statistics(&[1.0, 1.0]);
+
+        mutation.update(0, &stagnant);
+        assert_current(&mutation, 0.5, 2.0);
+
+        mutation.update(1, &stagnant);
+        assert_current(&mutation, 1.0, 6.0);
+
+        mutation.update(2, &diverse);
+        assert_current(&mutation, 0.5, 2.0);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+use rand_distr::{Distribution, StandardNormal};
+
+/// How many step sizes (σ) a chromosome carries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepSizes {
+    /// One σ shared by all genes.
+    Global,
+
+    /// Separate σ for each gene.
+    PerGene,
+}
+
+/// Evolution-strategy style mutation, where step sizes (σ) live inside
+/// each chromosome (see [`Chromosome::strategy()`]) and get mutated
+/// themselves (with log-normal updates) before they're used to perturb
+/// the genes.
+///
+/// Since children inherit their parents' step sizes, the mutation
+/// strength tunes itself: it stays large while that pays off and shrinks
+/// as the population converges.
+///
+/// All genes are perturbed, each by N(0, σ); chromosomes without step
+/// sizes (e.g. the initial population) start with `initial_sigma`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptiveMutation {
+    step_sizes: StepSizes,
+    initial_sigma: f32,
+
+    /// Lower bound of σ, preventing the search from freezing entirely.
+    min_sigma: f32,
+}
+
+impl SelfAdaptiveMutation {
+    pub fn new(step_sizes: StepSizes, initial_sigma: f32) -> Self {
+        assert!(initial_sigma > 0.0);
+
+        Self {
+            step_sizes,
+            initial_sigma,
+            min_sigma: 1e-5,
+        }
+    }
+
+    pub fn global(initial_sigma: f32) -> Self {
+        Self::new(StepSizes::Global, initial_sigma)
+    }
+
+    pub fn per_gene(initial_sigma: f32) -> Self {
+        Self::new(StepSizes::PerGene, initial_sigma)
+    }
+
+    pub fn with_min_sigma(mut self, min_sigma: f32) -> Self {
+        assert!(min_sigma >= 0.0);
+
+        self.min_sigma = min_sigma;
+        self
+    }
+}
+
+impl MutationMethod for SelfAdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome) {
+        if child.is_empty() {
+            return;
+        }
+
+        let n = child.len() as f32;
+
+        let expected_len = match self.step_sizes {
+            StepSizes::Global => 1,
+            StepSizes::PerGene => child.len(),
+        };
+
+        let mut strategy = child.strategy().to_vec();
+
+        if strategy.len() != expected_len {
+            strategy = vec![self.initial_sigma; expected_len];
+        }
+
+        // Learning rates, as recommended by Schwefel
+        match self.step_sizes {
+            StepSizes::Global => {
+                let tau = 1.0 / n.sqrt();
+                let noise: f32 = StandardNormal.sample(rng);
+
+                strategy[0] *= (tau * noise).exp();
+            }
+
+            StepSizes::PerGene => {
+                let tau_global = 1.0 / (2.0 * n).sqrt();
+                let tau_local = 1.0 / (2.0 * n.sqrt()).sqrt();
+                let global_noise: f32 = StandardNormal.sample(rng);
+
+                for sigma in strategy.iter_mut() {
+                    let local_noise: f32 = StandardNormal.sample(rng);
+
+                    *sigma *= (tau_global * global_noise + tau_local * local_noise).exp();
+                }
+            }
+        }
+
+        for sigma in strategy.iter_mut() {
+            *sigma = sigma.max(self.min_sigma);
+        }
+
+        for (idx, gene) in child.iter_mut().enumerate() {
+            let sigma = strategy[idx.min(strategy.len() - 1)];
+            let noise: f32 = StandardNormal.sample(rng);
+
+            *gene += sigma * noise;
+        }
+
+        child.set_strategy(strategy);
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::SelfAdaptive(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(mutation: SelfAdaptiveMutation) -> Chromosome {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        mutation.mutate(&mut rng, &mut child);
+        child
+    }
+
+    #[test]
+    fn global() {
+        let child = actual(SelfAdaptiveMutation::global(0.5));
+
+        let genes: Vec<_> = child.iter().cloned().collect();
+        let expected_genes = vec![1.3752981, 0.8924115, 1.2083995, 3.3780253, 4.0143046];
+
+        approx::assert_relative_eq!(genes.as_slice(), expected_genes.as_slice());
+        approx::assert_relative_eq!(child.strategy(), [0.9258692].as_ref());
+    }
+
+    #[test]
+    fn per_gene() {
+        let child = actual(SelfAdaptiveMutation::per_gene(0.5));
+
+        let genes: Vec<_> = child.iter().cloned().collect();
+        let expected_genes = vec![-1.0121167, 1.5598338, 2.8933418, 4.221422, 5.1705956];
+        let expected_strategy = vec![0.93631405, 0.43904135, 0.30958942, 0.5626243, 0.4672425];
+
+        approx::assert_relative_eq!(genes.as_slice(), expected_genes.as_slice());
+        approx::assert_relative_eq!(child.strategy(), expected_strategy.as_slice());
+    }
+
+    #[test]
+    fn keeps_sigma_above_the_minimum() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mutation = SelfAdaptiveMutation::per_gene(1e-3).with_min_sigma(1e-3);
+        let mut child: Chromosome = (0..10).map(|_| 0.0).collect();
+
+        for _ in 0..100 {
+            mutation.mutate(&mut rng, &mut child);
+        }
+
+        assert!(child.strategy().iter().all(|&sigma| sigma >= 1e-3));
+    }
+
+    #[test]
+    fn perturbs_genes_by_the_chromosomes_own_sigma() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mutation = SelfAdaptiveMutation::global(1.0).with_min_sigma(0.0);
+
+        let mut child: Chromosome = (0..10).map(|_| 0.0).collect();
+        child.set_strategy(vec![0.0]);
+
+        mutation.mutate(&mut rng, &mut child);
+
+        // Zero stays zero under log-normal updates, so the child should
+        // stay intact, no matter what `initial_sigma` says
+        assert!(child.iter().all(|&gene| gene == 0.0));
+    }
+}
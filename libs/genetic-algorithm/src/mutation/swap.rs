@@ -0,0 +1,67 @@
+use crate::{
+    chromosome::Chromosome,
+    mutation::{MutationMethod, MutationParameters},
+};
+
+/// Swaps two random genes, keeping permutations valid.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapMutation {
+    /// Probability of mutating a chromosome (not a gene):
+    /// - 0.0 = no chromosomes will be touched
+    /// - 1.0 = all chromosomes will be touched
+    chance: f32,
+}
+
+impl SwapMutation {
+    pub fn new(chance: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+
+        Self { chance }
+    }
+}
+
+impl<G> MutationMethod<G> for SwapMutation {
+    fn mutate(&self, rng: &mut dyn rand::RngCore, child: &mut Chromosome<G>) {
+        use rand::Rng;
+
+        if child.len() < 2 || !rng.gen_bool(self.chance as _) {
+            return;
+        }
+
+        let picked = rand::seq::index::sample(rng, child.len(), 2);
+
+        child.swap(picked.index(0), picked.index(1));
+    }
+
+    fn parameters(&self) -> MutationParameters {
+        MutationParameters::Swap(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<usize> {
+        let mut child = (0..10).collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        SwapMutation::new(chance)
+            .mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        assert_eq!(actual(0.0), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn given_max_chance_swaps_two_genes() {
+        assert_eq!(actual(1.0), vec![0, 1, 2, 3, 4, 5, 6, 9, 8, 7]);
+    }
+}
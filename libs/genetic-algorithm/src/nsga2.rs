@@ -0,0 +1,424 @@
+use crate::{
+    crossover::CrossoverMethod,
+    evaluation::{evaluate_objectives, MaybeSync},
+    individual::MultiObjectiveIndividual,
+    mutation::MutationMethod,
+    statistics::Statistics,
+};
+use std::cmp::Ordering;
+
+/// NSGA-II: evolves populations of [`MultiObjectiveIndividual`]s towards
+/// the Pareto front - i.e. the set of trade-offs where no objective can
+/// be improved without worsening another one - while keeping them spread
+/// evenly along it.
+///
+/// Each generation, parents are picked with binary tournaments (lower
+/// non-domination rank wins, ties go to the less crowded individual),
+/// and the next generation is made of the best individuals out of
+/// parents and offspring combined.
+pub struct Nsga2<G = f32> {
+    crossover_method: Box<dyn CrossoverMethod<G>>,
+    mutation_method: Box<dyn MutationMethod<G>>,
+    generation: usize,
+}
+
+impl<G> Nsga2<G>
+where
+    G: Clone,
+{
+    pub fn new(
+        crossover_method: impl CrossoverMethod<G> + 'static,
+        mutation_method: impl MutationMethod<G> + 'static,
+    ) -> Self {
+        Self {
+            crossover_method: Box::new(crossover_method),
+            mutation_method: Box::new(mutation_method),
+            generation: 0,
+        }
+    }
+
+    /// Index of the generation that's going to be evolved next.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Breeds a new generation out of `population`, of the same size.
+    ///
+    /// Before breeding, the mutation method gets a chance to adjust itself
+    /// (see [`MutationMethod::update()`]) to statistics of the first
+    /// objective - so fitness-driven schedules, such as the 1/5th rule,
+    /// follow that one.
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: &[I],
+    ) -> Vec<I>
+    where
+        I: MultiObjectiveIndividual<Gene = G> + MaybeSync,
+    {
+        assert!(!population.is_empty());
+
+        let objectives = evaluate_objectives(population);
+        let statistics = Statistics::new(objectives.iter().map(|objectives| objectives[0]));
+
+        self.mutation_method.update(self.generation, &statistics);
+        self.generation += 1;
+
+        let (ranks, crowding) = rank(&objectives);
+
+        let offspring: Vec<I> = (0..population.len())
+            .map(|_| {
+                let parent_a = population[tournament(rng, &ranks, &crowding)].chromosome();
+                let parent_b = population[tournament(rng, &ranks, &crowding)].chromosome();
+
+                let mut child = self
+                    .crossover_method
+                    .crossover(rng, parent_a, parent_b);
+
+                child.inherit_strategy(parent_a, parent_b);
+                self.mutation_method.mutate(rng, &mut child);
+                I::create(child)
+            })
+            .collect();
+
+        let offspring_objectives = evaluate_objectives(&offspring);
+
+        let candidates: Vec<I> = population
+            .iter()
+            .map(|parent| I::create(parent.chromosome().clone()))
+            .chain(offspring)
+            .collect();
+
+        let objectives: Vec<_> = objectives
+            .into_iter()
+            .chain(offspring_objectives)
+            .collect();
+
+        let mut survivors = Vec::with_capacity(population.len());
+
+        for front in non_dominated_sort(&objectives) {
+            if survivors.len() + front.len() <= population.len() {
+                survivors.extend(front);
+                continue;
+            }
+
+            // The front doesn't fit as a whole, so we keep its least
+            // crowded members, preserving the diversity
+            let distances = crowding_distance(&objectives, &front);
+            let mut front: Vec<_> = front.into_iter().zip(distances).collect();
+
+            front.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+            survivors.extend(
+                front
+                    .into_iter()
+                    .take(population.len() - survivors.len())
+                    .map(|(idx, _)| idx),
+            );
+
+            break;
+        }
+
+        let mut candidates: Vec<_> = candidates.into_iter().map(Some).collect();
+
+        survivors
+            .into_iter()
+            .map(|idx| candidates[idx].take().unwrap())
+            .collect()
+    }
+}
+
+/// Returns non-dominated members of `population`, i.e. its Pareto front.
+pub fn pareto_front<I>(population: &[I]) -> Vec<&I>
+where
+    I: MultiObjectiveIndividual + MaybeSync,
+{
+    let objectives = evaluate_objectives(population);
+
+    non_dominated_sort(&objectives)
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|idx| &population[idx])
+        .collect()
+}
+
+/// Whether `a` is at least as good as `b` in every objective and better
+/// in at least one.
+fn dominates(a: &[f32], b: &[f32]) -> bool {
+    assert_eq!(a.len(), b.len());
+
+    a.iter().zip(b).all(|(a, b)| a >= b) && a.iter().zip(b).any(|(a, b)| a > b)
+}
+
+/// Splits individuals into fronts (returning their indices): the first
+/// front contains individuals nobody dominates, the second one those
+/// dominated only by the first front, and so on.
+fn non_dominated_sort(objectives: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    let len = objectives.len();
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); len];
+    let mut domination_count = vec![0; len];
+
+    for a in 0..len {
+        for b in 0..len {
+            if dominates(&objectives[a], &objectives[b]) {
+                dominated[a].push(b);
+            } else if dominates(&objectives[b], &objectives[a]) {
+                domination_count[a] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+
+    let mut front: Vec<usize> = (0..len)
+        .filter(|&idx| domination_count[idx] == 0)
+        .collect();
+
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+
+        for &a in &front {
+            for &b in &dominated[a] {
+                domination_count[b] -= 1;
+
+                if domination_count[b] == 0 {
+                    next_front.push(b);
+                }
+            }
+        }
+
+        fronts.push(front);
+        front = next_front;
+    }
+
+    fronts
+}
+
+/// Returns how much room there is around each member of `front` (with
+/// boundary members getting infinity), measured along all the objectives.
+fn crowding_distance(objectives: &[Vec<f32>], front: &[usize]) -> Vec<f32> {
+    let mut distances = vec![0.0; front.len()];
+
+    if front.is_empty() {
+        return distances;
+    }
+
+    // Values of each objective, across the front
+    let columns = (0..objectives[front[0]].len()).map(|objective| {
+        front
+            .iter()
+            .map(|&idx| objectives[idx][objective])
+            .collect::<Vec<_>>()
+    });
+
+    for values in columns {
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+
+        let first = order[0];
+        let last = order[order.len() - 1];
+        let range = values[last] - values[first];
+
+        distances[first] = f32::INFINITY;
+        distances[last] = f32::INFINITY;
+
+        if range <= 0.0 {
+            continue;
+        }
+
+        for window in order.windows(3) {
+            distances[window[1]] += (values[window[2]] - values[window[0]]) / range;
+        }
+    }
+
+    distances
+}
+
+/// Returns each individual's rank (index of its front) and crowding
+/// distance (within its front).
+fn rank(objectives: &[Vec<f32>]) -> (Vec<usize>, Vec<f32>) {
+    let mut ranks = vec![0; objectives.len()];
+    let mut crowding = vec![0.0; objectives.len()];
+
+    for (rank, front) in non_dominated_sort(objectives).into_iter().enumerate() {
+        for (&idx, distance) in front.iter().zip(crowding_distance(objectives, &front)) {
+            ranks[idx] = rank;
+            crowding[idx] = distance;
+        }
+    }
+
+    (ranks, crowding)
+}
+
+/// Binary tournament on (rank, crowding distance); returns the winner's
+/// index.
+fn tournament(rng: &mut dyn rand::RngCore, ranks: &[usize], crowding: &[f32]) -> usize {
+    use rand::Rng;
+
+    let a = rng.gen_range(0..ranks.len());
+    let b = rng.gen_range(0..ranks.len());
+
+    match ranks[a].cmp(&ranks[b]) {
+        Ordering::Less => a,
+        Ordering::Greater => b,
+        Ordering::Equal => if crowding[a] >= crowding[b] { a } else { b },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chromosome::Chromosome,
+        crossover::BlendCrossover,
+        mutation::{GaussianMutation, MutationSchedule, NormalMutation, ScheduledMutation},
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// Schaffer's problem: minimize both `x²` and `(x - 2)²`, whose
+    /// Pareto front lies at `0.0 <= x <= 2.0`.
+    #[derive(Debug)]
+    struct Schaffer(Chromosome);
+
+    impl MultiObjectiveIndividual for Schaffer {
+        type Gene = f32;
+
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+
+        fn objectives(&self) -> Vec<f32> {
+            let x = self.0[0];
+
+            vec![-x.powi(2), -(x - 2.0).powi(2)]
+        }
+    }
+
+    fn points(points: &[(f32, f32)]) -> Vec<Vec<f32>> {
+        points.iter().map(|&(a, b)| vec![a, b]).collect()
+    }
+
+    #[test]
+    fn test_dominates() {
+        assert!(dominates(&[1.0, 2.0], &[1.0, 1.0]));
+        assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0]));
+        assert!(!dominates(&[2.0, 1.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_non_dominated_sort() {
+        let objectives = points(&[
+            (1.0, 5.0),
+            (2.0, 2.0),
+            (5.0, 1.0),
+            (1.0, 1.0),
+            (3.0, 3.0),
+            (0.0, 0.0),
+        ]);
+
+        assert_eq!(
+            non_dominated_sort(&objectives),
+            vec![vec![0, 2, 4], vec![1], vec![3], vec![5]],
+        );
+    }
+
+    #[test]
+    fn test_crowding_distance() {
+        let objectives = points(&[(0.0, 4.0), (1.0, 3.0), (3.0, 1.0), (4.0, 0.0)]);
+        let distances = crowding_distance(&objectives, &[0, 1, 2, 3]);
+
+        assert_eq!(distances[0], f32::INFINITY);
+        assert_eq!(distances[3], f32::INFINITY);
+
+        // (3 - 0) / 4, summed over both objectives
+        approx::assert_relative_eq!(distances[1], 1.5);
+        approx::assert_relative_eq!(distances[2], 1.5);
+    }
+
+    #[test]
+    fn test_pareto_front() {
+        let population: Vec<_> = [-1.0, 0.5, 1.0, 3.0]
+            .iter()
+            .map(|&x| Schaffer::create(vec![x].into_iter().collect()))
+            .collect();
+
+        let front: Vec<_> = pareto_front(&population)
+            .into_iter()
+            .map(|individual| individual.chromosome()[0])
+            .collect();
+
+        assert_eq!(front, vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn converges_to_the_pareto_front() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(BlendCrossover::new(0.5), NormalMutation::new(0.2, 0.1));
+
+        let mut population: Vec<_> = (0..20)
+            .map(|_| Schaffer::create(vec![rng.gen_range(-10.0..10.0)].into_iter().collect()))
+            .collect();
+
+        for _ in 0..30 {
+            population = nsga2.evolve(&mut rng, &population);
+        }
+
+        assert_eq!(population.len(), 20);
+        assert_eq!(nsga2.generation(), 30);
+
+        let xs: Vec<_> = population
+            .iter()
+            .map(|individual| individual.chromosome()[0])
+            .collect();
+
+        assert!(xs.iter().all(|x| (-0.1..=2.1).contains(x)));
+
+        // ... and spread along it, instead of clumping in one spot
+        let min = xs.iter().cloned().fold(f32::INFINITY, f32::min);
+        let max = xs.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+        assert!(max - min > 1.5);
+    }
+    #[test]
+    fn updates_mutation_method() {
+        // Decays into no mutation at all right away - provided that it's
+        // been updated
+        let scheduled = ScheduledMutation::new(
+            GaussianMutation::new(1.0, 1.0),
+            MutationSchedule::LinearDecay {
+                final_chance: 0.0,
+                final_coeff: 0.0,
+                generations: 0,
+            },
+        );
+
+        let run = |mut nsga2: Nsga2| {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let mut population: Vec<_> = (0..10)
+                .map(|_| Schaffer::create(vec![rng.gen_range(-10.0..10.0)].into_iter().collect()))
+                .collect();
+
+            for _ in 0..5 {
+                population = nsga2.evolve(&mut rng, &population);
+            }
+
+            population
+                .iter()
+                .map(|individual| individual.chromosome()[0])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            run(Nsga2::new(BlendCrossover::new(0.5), scheduled)),
+            run(Nsga2::new(BlendCrossover::new(0.5), GaussianMutation::new(0.0, 0.0))),
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    evaluation::MaybeSync,
+    individual::Individual,
+    selection::SelectionMethod,
+    statistics::Statistics,
+    termination::{Outcome, StopCondition},
+    GeneticAlgorithm,
+};
+use std::time::{Duration, Instant};
+
+/// Common interface of everything that turns one population into the
+/// next one - so that callers (e.g. the simulation) can switch between
+/// optimization backends without caring which one they've got.
+pub trait Optimizer<I> {
+    /// Creates the next population out of `population`, returning it
+    /// along with statistics of the (parent) `population`.
+    fn evolve(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: &[I],
+    ) -> (Vec<I>, Statistics);
+
+    /// Evolves `population` generation after generation, until `stop`
+    /// says it's enough.
+    ///
+    /// Conditions are checked before evolving each generation, against
+    /// statistics of the generations evolved so far - so e.g.
+    /// [`StopCondition::MaxGenerations`]`(n)` evolves exactly `n` of them
+    /// and returns the offspring of the last one. That returned population
+    /// hasn't been evaluated yet, so - when it matters - keep the best
+    /// individuals around with some elitism.
+    ///
+    /// Conditions that aren't guaranteed to be met (e.g. a target fitness
+    /// that's never reached) are best combined with
+    /// [`StopCondition::MaxGenerations`]; panics if `stop` contains an
+    /// empty [`StopCondition::Any`] or [`StopCondition::All`], since those
+    /// could never be met.
+    fn run(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: Vec<I>,
+        stop: &StopCondition,
+    ) -> Outcome<I>
+    {
+        run_loop(self, rng, population, Vec::new(), stop, |_, _, _, _| ())
+    }
+}
+
+/// Evolves `population` until `stop` says it's enough, picking up after
+/// the generations described by `history`; `after_generation` gets called
+/// with the optimizer, the random number generator, the population that's
+/// going to be evolved next and the history, after every generation that
+/// hasn't stopped the run.
+pub(crate) fn run_loop<O, I, R>(
+    optimizer: &mut O,
+    mut rng: &mut R,
+    population: Vec<I>,
+    history: Vec<Statistics>,
+    stop: &StopCondition,
+    mut after_generation: impl FnMut(&O, &R, &[I], &[Statistics]),
+) -> Outcome<I>
+where
+    O: Optimizer<I> + ?Sized,
+    R: rand::RngCore + ?Sized,
+{
+    assert!(
+        !stop.has_empty_group(),
+        "stop condition contains an empty Any or All, which can never be met",
+    );
+
+    // `Instant::now()` panics on `wasm32-unknown-unknown`, so the clock is
+    // only touched when it's actually needed
+    let started_at = stop.uses_clock().then(Instant::now);
+    let elapsed = || started_at.map_or(Duration::ZERO, |started_at| started_at.elapsed());
+    let mut population = population;
+    let mut history = history;
+    let mut stop_reasons = stop.check(&history, elapsed());
+
+    while stop_reasons.is_empty() {
+        // (`&mut &mut R` is what can be made into `&mut dyn RngCore` when
+        // `R` itself is unsized)
+        let (new_population, statistics) = optimizer.evolve(&mut rng, &population);
+
+        population = new_population;
+        history.push(statistics);
+        stop_reasons = stop.check(&history, elapsed());
+
+        if stop_reasons.is_empty() {
+            after_generation(optimizer, rng, &population, &history);
+        }
+    }
+
+    Outcome::new(population, history, stop_reasons)
+}
+
+impl<S, G, I> Optimizer<I> for GeneticAlgorithm<S, G>
+where
+    S: SelectionMethod,
+    G: Clone,
+    I: Individual<Gene = G> + MaybeSync,
+{
+    fn evolve(
+        &mut self,
+        rng: &mut dyn rand::RngCore,
+        population: &[I],
+    ) -> (Vec<I>, Statistics)
+    {
+        GeneticAlgorithm::evolve(self, rng, population)
+    }
+}
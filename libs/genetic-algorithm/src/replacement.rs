@@ -0,0 +1,31 @@
+/// Describes how the next generation is assembled out of the current
+/// one (the parents, μ of them) and the offspring bred from it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Replacement {
+    /// Offspring replace the whole population, except for the `elitism`
+    /// fittest parents, which are carried over unchanged:
+    /// - 0 = every generation consists of offspring only
+    /// - 1 = the best individual found so far is never lost
+    Generational { elitism: usize },
+
+    /// (μ+λ): breeds `offspring` (λ) children and keeps the μ fittest
+    /// individuals out of parents and children combined.
+    ///
+    /// Fitness of children is read right after they're created, so it
+    /// has to be computable from their chromosomes alone.
+    Plus { offspring: usize },
+
+    /// (μ,λ): breeds `offspring` (λ, at least μ) children and keeps the
+    /// μ fittest ones out of them, discarding all the parents.
+    ///
+    /// Fitness of children is read right after they're created, so it
+    /// has to be computable from their chromosomes alone.
+    Comma { offspring: usize },
+}
+
+impl Default for Replacement {
+    fn default() -> Self {
+        Self::Generational { elitism: 0 }
+    }
+}
@@ -1,9 +1,17 @@
+mod rank;
 mod roulette_wheel;
+mod stochastic_universal_sampling;
+mod tournament;
 
-pub use self::roulette_wheel::*;
+pub use self::{
+    rank::*,
+    roulette_wheel::*,
+    stochastic_universal_sampling::*,
+    tournament::*,
+};
 
 use crate::{
-    individual::Individual,
+    individual::Fitness,
 };
 
 pub trait SelectionMethod {
@@ -13,5 +21,42 @@ pub trait SelectionMethod {
         population: &'a [I],
     ) -> &'a I
     where
-        I: Individual;
+        I: Fitness;
+
+    /// Picks `count` individuals at once (e.g. all parents of the next
+    /// generation); methods that need to see the whole batch, such as
+    /// [`StochasticUniversalSampling`], override it, while the rest
+    /// simply `select()` repeatedly.
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Fitness,
+    {
+        (0..count)
+            .map(|_| self.select(rng, population))
+            .collect()
+    }
+
+    /// Parameters this method has been built with; a run saved in a
+    /// [`Checkpoint`](crate::checkpoint::Checkpoint) is only ever resumed
+    /// with a method that reports the same ones.
+    fn parameters(&self) -> SelectionParameters;
+}
+
+/// Parameters of a [`SelectionMethod`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectionParameters {
+    Rank(RankSelection),
+    RouletteWheel,
+    StochasticUniversalSampling,
+    Tournament(TournamentSelection),
+
+    /// Method defined outside of this crate, described by itself - e.g. as
+    /// `"boltzmann(temperature=0.5)"`.
+    Custom(String),
 }
@@ -0,0 +1,257 @@
+use crate::{
+    individual::Fitness,
+    selection::{SelectionMethod, SelectionParameters},
+};
+
+/// Describes how an individual's rank (its position after sorting the
+/// population by fitness) translates into its chances of being picked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ranking {
+    /// Chances grow linearly with the rank; `pressure` ranges from:
+    /// - 1.0 = everybody has the same chances
+    /// - 2.0 = the fittest individual is twice as likely to be picked
+    ///   as an average one, while the weakest one is never picked
+    Linear { pressure: f32 },
+
+    /// The fittest individual gets a weight of 1.0, the second-fittest
+    /// `base`, the third-fittest `base^2` and so on; `base` ranges from:
+    /// - close to 0.0 = (almost) always pick the fittest
+    /// - 1.0 = everybody has the same chances
+    Exponential { base: f32 },
+}
+
+/// Like [`RouletteWheelSelection`](super::RouletteWheelSelection), but
+/// weighted by rank instead of raw fitness, so it's not affected by how
+/// fitness is scaled and works with zero and negative fitness too.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RankSelection {
+    ranking: Ranking,
+}
+
+impl RankSelection {
+    pub fn new(ranking: Ranking) -> Self {
+        match ranking {
+            Ranking::Linear { pressure } => {
+                assert!((1.0..=2.0).contains(&pressure));
+            }
+
+            Ranking::Exponential { base } => {
+                assert!(base > 0.0 && base <= 1.0);
+            }
+        }
+
+        Self { ranking }
+    }
+
+    pub fn linear(pressure: f32) -> Self {
+        Self::new(Ranking::Linear { pressure })
+    }
+
+    pub fn exponential(base: f32) -> Self {
+        Self::new(Ranking::Exponential { base })
+    }
+
+    /// Returns weight of each individual, in the population's order.
+    fn weights<I>(&self, population: &[I]) -> Vec<f32>
+    where
+        I: Fitness,
+    {
+        let mut by_fitness: Vec<_> = (0..population.len()).collect();
+
+        // Weakest first, so that an individual's position is its rank
+        by_fitness.sort_by(|&a, &b| {
+            population[a]
+                .fitness()
+                .partial_cmp(&population[b].fitness())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let len = population.len();
+        let mut weights = vec![0.0; len];
+
+        for (rank, idx) in by_fitness.into_iter().enumerate() {
+            weights[idx] = match self.ranking {
+                Ranking::Linear { pressure } => {
+                    if len == 1 {
+                        1.0
+                    } else {
+                        (2.0 - pressure)
+                            + 2.0 * (pressure - 1.0) * (rank as f32) / ((len - 1) as f32)
+                    }
+                }
+
+                Ranking::Exponential { base } => {
+                    base.powi((len - 1 - rank) as i32)
+                }
+            };
+        }
+
+        weights
+    }
+}
+
+impl SelectionMethod for RankSelection {
+    fn select<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I]
+    ) -> &'a I
+    where
+        I: Fitness,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Fitness,
+    {
+        use rand::distributions::{Distribution, WeightedIndex};
+
+        assert!(!population.is_empty(), "got an empty population");
+
+        let weights = self.weights(population);
+
+        // The fittest individual always weighs at least 1.0, so there's
+        // at least one positive weight
+        let dist = WeightedIndex::new(&weights).unwrap();
+
+        (0..count)
+            .map(|_| &population[dist.sample(rng)])
+            .collect()
+    }
+
+    fn parameters(&self) -> SelectionParameters {
+        SelectionParameters::Rank(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha8Rng;
+    use rand::SeedableRng;
+    use std::{
+        collections::BTreeMap,
+        iter::FromIterator,
+    };
+    use crate::{
+        individual::TestIndividual,
+    };
+
+    fn histogram(
+        method: RankSelection,
+        population: &[TestIndividual],
+    ) -> BTreeMap<i32, usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        (0..1000)
+            .map(|_| method.select(&mut rng, population))
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram
+                    .entry(individual.fitness() as _)
+                    .or_default() += 1;
+
+                histogram
+            })
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(400.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    #[test]
+    #[should_panic]
+    fn linear_with_too_high_pressure_panics() {
+        RankSelection::linear(2.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponential_with_zero_base_panics() {
+        RankSelection::exponential(0.0);
+    }
+
+    mod weights {
+        use super::*;
+
+        #[test]
+        fn linear() {
+            let actual = RankSelection::linear(1.5).weights(&population());
+            let expected = vec![0.8333333, 0.5, 1.5, 1.1666666];
+
+            approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+        }
+
+        #[test]
+        fn exponential() {
+            let actual = RankSelection::exponential(0.5).weights(&population());
+            let expected = vec![0.25, 0.125, 1.0, 0.5];
+
+            approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn select_with_linear_ranking_gives_correct_proportions() {
+        let actual_histogram = histogram(RankSelection::linear(2.0), &population());
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (2, 164),
+            (3, 318),
+            (400, 518),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_with_exponential_ranking_gives_correct_proportions() {
+        let actual_histogram = histogram(RankSelection::exponential(0.5), &population());
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (1, 60),
+            (2, 142),
+            (3, 247),
+            (400, 551),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_handles_zero_and_negative_fitness() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(-1.0),
+            TestIndividual::new(-3.0),
+            TestIndividual::new(-2.0),
+        ];
+
+        let actual_histogram = histogram(RankSelection::linear(1.5), &population);
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (-3, 127),
+            (-2, 191),
+            (-1, 307),
+            (0, 375),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+}
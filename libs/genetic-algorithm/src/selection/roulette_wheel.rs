@@ -1,8 +1,10 @@
 use crate::{
-    individual::Individual,
-    selection::SelectionMethod,
+    individual::Fitness,
+    selection::{SelectionMethod, SelectionParameters},
 };
 
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouletteWheelSelection;
 
 impl RouletteWheelSelection {
@@ -18,12 +20,23 @@ impl SelectionMethod for RouletteWheelSelection {
         population: &'a [I]
     ) -> &'a I
     where
-        I: Individual,
+        I: Fitness,
     {
-        use rand::seq::SliceRandom;
-        population
-            .choose_weighted(rng, |individual| individual.fitness())
-            .expect("got an empty population")
+        use rand::{distributions::WeightedError, seq::SliceRandom};
+
+        match population.choose_weighted(&mut *rng, |individual| individual.fitness()) {
+            // Nobody has scored anything yet, so everybody's equally
+            // good (or bad) a parent
+            Err(WeightedError::AllWeightsZero) => {
+                population.choose(rng).expect("got an empty population")
+            }
+
+            result => result.expect("got an empty population"),
+        }
+    }
+
+    fn parameters(&self) -> SelectionParameters {
+        SelectionParameters::RouletteWheel
     }
 }
 
@@ -70,4 +83,38 @@ mod tests {
 
         assert_eq!(actual_histogram, expected_histogram);
     }
+
+    #[test]
+    fn select_falls_back_to_uniform_when_nobody_is_fit() {
+        let method = RouletteWheelSelection::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+        ];
+        let actual_histogram: BTreeMap<usize, _> = (0..1000)
+            .map(|_| method.select(&mut rng, &population))
+            .fold(Default::default(), |mut histogram, individual| {
+                let idx = population
+                    .iter()
+                    .position(|other| std::ptr::eq(other, individual))
+                    .unwrap();
+
+                *histogram.entry(idx).or_default() += 1;
+
+                histogram
+            });
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (index, how many times this individual has been chosen)
+            (0, 246),
+            (1, 262),
+            (2, 254),
+            (3, 238),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
 }
@@ -0,0 +1,195 @@
+use crate::{
+    individual::Fitness,
+    selection::{SelectionMethod, SelectionParameters},
+};
+
+/// Fitness-proportionate selection, like
+/// [`RouletteWheelSelection`](super::RouletteWheelSelection), but picking
+/// all individuals in a single spin of a wheel with `count` evenly spaced
+/// pointers - so that the number of times an individual is picked never
+/// strays far from its expected value.
+///
+/// Fitness must not be negative.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticUniversalSampling;
+
+impl StochasticUniversalSampling {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SelectionMethod for StochasticUniversalSampling {
+    fn select<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I]
+    ) -> &'a I
+    where
+        I: Fitness,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Fitness,
+    {
+        use rand::{Rng, seq::SliceRandom};
+
+        assert!(!population.is_empty(), "got an empty population");
+
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let mut weights: Vec<_> = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
+
+        assert!(
+            weights.iter().all(|&weight| weight >= 0.0),
+            "got a negative fitness",
+        );
+
+        // Nobody has scored anything yet, so everybody's equally good (or
+        // bad) a parent
+        if weights.iter().all(|&weight| weight == 0.0) {
+            weights = vec![1.0; population.len()];
+        }
+
+        let total: f32 = weights.iter().sum();
+        let spacing = total / (count as f32);
+        let start = rng.gen_range(0.0..spacing);
+
+        let mut selected = Vec::with_capacity(count);
+        let mut idx = 0;
+        let mut cumulative = weights[0];
+
+        for pointer in 0..count {
+            let pointer = start + (pointer as f32) * spacing;
+
+            // The last check guards against rounding errors pushing the
+            // final pointer past the end of the wheel
+            while pointer >= cumulative && idx + 1 < population.len() {
+                idx += 1;
+                cumulative += weights[idx];
+            }
+
+            selected.push(&population[idx]);
+        }
+
+        // Pointers walk the wheel in order, so without shuffling, similar
+        // individuals would always end up next to each other
+        selected.shuffle(rng);
+        selected
+    }
+
+    fn parameters(&self) -> SelectionParameters {
+        SelectionParameters::StochasticUniversalSampling
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha8Rng;
+    use rand::SeedableRng;
+    use std::{
+        collections::BTreeMap,
+        iter::FromIterator,
+    };
+    use crate::{
+        individual::TestIndividual,
+    };
+
+    fn histogram(population: &[TestIndividual], count: usize) -> BTreeMap<i32, usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        StochasticUniversalSampling::new()
+            .select_many(&mut rng, population, count)
+            .into_iter()
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram
+                    .entry(individual.fitness() as _)
+                    .or_default() += 1;
+
+                histogram
+            })
+    }
+
+    #[test]
+    fn select_many_gives_exact_proportions() {
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let actual_histogram = histogram(&population, 1000);
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (1, 100),
+            (2, 200),
+            (3, 300),
+            (4, 400),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_many_never_strays_far_from_expected_counts() {
+        let population = vec![
+            TestIndividual::new(1.0),
+            TestIndividual::new(2.0),
+            TestIndividual::new(4.0),
+        ];
+
+        let actual_histogram = histogram(&population, 10);
+
+        // Expected counts are 10/7, 20/7 and 40/7
+        assert!((1..=2).contains(&actual_histogram[&1]));
+        assert!((2..=3).contains(&actual_histogram[&2]));
+        assert!((5..=6).contains(&actual_histogram[&4]));
+    }
+
+    #[test]
+    fn select_many_falls_back_to_uniform_when_nobody_is_fit() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let selected = StochasticUniversalSampling::new()
+            .select_many(&mut rng, &population, 10);
+
+        let first = selected
+            .iter()
+            .filter(|&&individual| std::ptr::eq(individual, &population[0]))
+            .count();
+
+        assert_eq!(first, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "got a negative fitness")]
+    fn select_many_with_negative_fitness_panics() {
+        let population = vec![
+            TestIndividual::new(1.0),
+            TestIndividual::new(-1.0),
+        ];
+
+        histogram(&population, 10);
+    }
+}
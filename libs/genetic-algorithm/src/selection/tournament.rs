@@ -0,0 +1,187 @@
+use crate::{
+    individual::Fitness,
+    selection::{SelectionMethod, SelectionParameters},
+};
+
+/// Picks a handful of random individuals and lets the fittest of them
+/// win; since only the order of fitness scores matters, this works
+/// with zero and negative fitness too.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TournamentSelection {
+    /// How many individuals take part in each tournament:
+    /// - 1 = no selective pressure at all (random choice)
+    /// - the larger, the lower chances of weak individuals
+    size: usize,
+
+    /// Probability of the fittest contestant winning the tournament:
+    /// - 1.0 = the fittest contestant always wins
+    /// - 0.5 = the fittest wins half of the time, the second-fittest a
+    ///   quarter of the time, and so on
+    probability: f32,
+}
+
+impl TournamentSelection {
+    pub fn new(size: usize) -> Self {
+        Self::with_probability(size, 1.0)
+    }
+
+    pub fn with_probability(size: usize, probability: f32) -> Self {
+        assert!(size > 0);
+        assert!(probability > 0.0 && probability <= 1.0);
+
+        Self { size, probability }
+    }
+}
+
+impl SelectionMethod for TournamentSelection {
+    fn select<'a, I>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        population: &'a [I]
+    ) -> &'a I
+    where
+        I: Fitness,
+    {
+        use rand::{Rng, seq::SliceRandom};
+
+        let mut contestants: Vec<_> = (0..self.size)
+            .map(|_| population.choose(rng).expect("got an empty population"))
+            .collect();
+
+        // Fittest first
+        contestants.sort_by(|a, b| {
+            b.fitness()
+                .partial_cmp(&a.fitness())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let (last, rest) = contestants
+            .split_last()
+            .expect("got an empty tournament");
+
+        rest.iter()
+            .find(|_| rng.gen_bool(self.probability as _))
+            .unwrap_or(last)
+    }
+
+    fn parameters(&self) -> SelectionParameters {
+        SelectionParameters::Tournament(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha8Rng;
+    use rand::SeedableRng;
+    use std::{
+        collections::BTreeMap,
+        iter::FromIterator,
+    };
+    use crate::{
+        individual::TestIndividual,
+    };
+
+    fn histogram(
+        method: TournamentSelection,
+        population: &[TestIndividual],
+    ) -> BTreeMap<i32, usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        (0..1000)
+            .map(|_| method.select(&mut rng, population))
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram
+                    .entry(individual.fitness() as _)
+                    .or_default() += 1;
+
+                histogram
+            })
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_with_zero_size_panics() {
+        TournamentSelection::new(0);
+    }
+
+    #[test]
+    fn select_with_size_of_one_is_uniform() {
+        let actual_histogram = histogram(TournamentSelection::new(1), &population());
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (1, 262),
+            (2, 246),
+            (3, 238),
+            (4, 254),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_gives_correct_proportions() {
+        let actual_histogram = histogram(TournamentSelection::new(2), &population());
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (1, 66),
+            (2, 179),
+            (3, 299),
+            (4, 456),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_with_probability_lets_weaker_contestants_win() {
+        let actual_histogram = histogram(
+            TournamentSelection::with_probability(2, 0.75),
+            &population(),
+        );
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (1, 169),
+            (2, 206),
+            (3, 264),
+            (4, 361),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_handles_zero_and_negative_fitness() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(-1.0),
+            TestIndividual::new(-3.0),
+            TestIndividual::new(-2.0),
+        ];
+
+        let actual_histogram = histogram(TournamentSelection::new(2), &population);
+
+        let expected_histogram = BTreeMap::from_iter(vec![
+            // (fitness, how many times this fitness has been chosen)
+            (-3, 70),
+            (-2, 175),
+            (-1, 330),
+            (0, 425),
+        ]);
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+}
@@ -0,0 +1,130 @@
+/// Summary of the fitness scores within a population.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Statistics {
+    min_fitness: f32,
+    max_fitness: f32,
+    mean_fitness: f32,
+    median_fitness: f32,
+    std_dev_fitness: f32,
+}
+
+impl Statistics {
+    /// Summarizes given fitness scores, e.g.
+    /// `Statistics::new(population.iter().map(Individual::fitness))`.
+    pub fn new(fitnesses: impl IntoIterator<Item = f32>) -> Self {
+        let mut fitnesses: Vec<_> = fitnesses.into_iter().collect();
+
+        assert!(!fitnesses.is_empty());
+
+        fitnesses.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let len = fitnesses.len() as f32;
+        let min_fitness = fitnesses[0];
+        let max_fitness = fitnesses[fitnesses.len() - 1];
+        let mean_fitness = fitnesses.iter().sum::<f32>() / len;
+
+        let median_fitness = if fitnesses.len() % 2 == 0 {
+            let mid = fitnesses.len() / 2;
+            (fitnesses[mid - 1] + fitnesses[mid]) / 2.0
+        } else {
+            fitnesses[fitnesses.len() / 2]
+        };
+
+        let std_dev_fitness = (fitnesses
+            .iter()
+            .map(|fitness| (fitness - mean_fitness).powi(2))
+            .sum::<f32>()
+            / len)
+            .sqrt();
+
+        Self {
+            min_fitness,
+            max_fitness,
+            mean_fitness,
+            median_fitness,
+            std_dev_fitness,
+        }
+    }
+
+    pub fn min_fitness(&self) -> f32 {
+        self.min_fitness
+    }
+
+    pub fn max_fitness(&self) -> f32 {
+        self.max_fitness
+    }
+
+    pub fn mean_fitness(&self) -> f32 {
+        self.mean_fitness
+    }
+
+    pub fn median_fitness(&self) -> f32 {
+        self.median_fitness
+    }
+
+    /// Population standard deviation of the fitness scores.
+    pub fn std_dev_fitness(&self) -> f32 {
+        self.std_dev_fitness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics(fitnesses: &[f32]) -> Statistics {
+        Statistics::new(fitnesses.iter().copied())
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_with_empty_population_panics() {
+        statistics(&[]);
+    }
+
+    mod given_odd_population {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let actual = statistics(&[30.0, 10.0, 20.0, 50.0, 40.0]);
+
+            approx::assert_relative_eq!(actual.min_fitness(), 10.0);
+            approx::assert_relative_eq!(actual.max_fitness(), 50.0);
+            approx::assert_relative_eq!(actual.mean_fitness(), 30.0);
+            approx::assert_relative_eq!(actual.median_fitness(), 30.0);
+            approx::assert_relative_eq!(actual.std_dev_fitness(), 200.0_f32.sqrt());
+        }
+    }
+
+    mod given_even_population {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let actual = statistics(&[30.0, 10.0, 20.0, 60.0]);
+
+            approx::assert_relative_eq!(actual.min_fitness(), 10.0);
+            approx::assert_relative_eq!(actual.max_fitness(), 60.0);
+            approx::assert_relative_eq!(actual.mean_fitness(), 30.0);
+            approx::assert_relative_eq!(actual.median_fitness(), 25.0);
+            approx::assert_relative_eq!(actual.std_dev_fitness(), 350.0_f32.sqrt());
+        }
+    }
+
+    mod given_uniform_population {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let actual = statistics(&[5.0, 5.0, 5.0]);
+
+            approx::assert_relative_eq!(actual.min_fitness(), 5.0);
+            approx::assert_relative_eq!(actual.max_fitness(), 5.0);
+            approx::assert_relative_eq!(actual.mean_fitness(), 5.0);
+            approx::assert_relative_eq!(actual.median_fitness(), 5.0);
+            approx::assert_relative_eq!(actual.std_dev_fitness(), 0.0);
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use crate::{
+    statistics::Statistics,
+};
+use std::time::Duration;
+
+/// Decides when [`Optimizer::run()`](crate::optimizer::Optimizer::run)
+/// should stop; conditions can be composed with [`StopCondition::Any`]
+/// and [`StopCondition::All`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopCondition {
+    /// Stops once this many generations have been evolved; `0` returns the
+    /// initial population as-is.
+    MaxGenerations(usize),
+
+    /// Stops once the fittest individual of the last evolved generation
+    /// reaches this fitness.
+    TargetFitness(f32),
+
+    /// Stops once the best fitness hasn't improved by more than
+    /// `tolerance` over the last `generations` generations.
+    ///
+    /// There has to be at least one generation before those to compare
+    /// against, so `generations: 0` stops right after the first one.
+    Plateau {
+        generations: usize,
+        tolerance: f32,
+    },
+
+    /// Stops once the run has taken this long (checked in between
+    /// generations, so it can overshoot by up to one generation).
+    ///
+    /// Relies on `std::time::Instant`, which isn't available on
+    /// `wasm32-unknown-unknown` - the clock is read only when the stop
+    /// condition contains this variant, so all the other ones work there
+    /// just fine.
+    TimeBudget(Duration),
+
+    /// Stops once the standard deviation of fitness drops below
+    /// `min_std_dev`.
+    ///
+    /// Note that it looks at fitness only, not at the chromosomes - a
+    /// population of different individuals that all score the same counts
+    /// as collapsed, while a converged one never does when the fitness
+    /// function is noisy enough.
+    FitnessSpreadCollapse {
+        min_std_dev: f32,
+    },
+
+    /// Stops once any of the conditions is met; must not be empty.
+    Any(Vec<StopCondition>),
+
+    /// Stops once all of the conditions are met at the same time; must
+    /// not be empty.
+    All(Vec<StopCondition>),
+}
+
+/// Basic condition that has caused a run to stop.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopReason {
+    MaxGenerations,
+    TargetFitness,
+    Plateau,
+    TimeBudget,
+    FitnessSpreadCollapse,
+}
+
+impl StopCondition {
+    /// Whether this condition contains an empty [`StopCondition::Any`] or
+    /// [`StopCondition::All`] anywhere, i.e. one that can never be met.
+    pub(crate) fn has_empty_group(&self) -> bool {
+        match self {
+            Self::Any(conditions) | Self::All(conditions) => {
+                conditions.is_empty() || conditions.iter().any(Self::has_empty_group)
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Whether this condition needs to know how long the run has taken
+    /// so far.
+    pub(crate) fn uses_clock(&self) -> bool {
+        match self {
+            Self::TimeBudget(_) => true,
+            Self::Any(conditions) | Self::All(conditions) => {
+                conditions.iter().any(Self::uses_clock)
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Returns reasons to stop given statistics of all the generations
+    /// evolved so far (oldest first), or nothing if the run should go on.
+    pub(crate) fn check(&self, history: &[Statistics], elapsed: Duration) -> Vec<StopReason> {
+        let latest = history.last();
+
+        let (met, reason) = match self {
+            Self::MaxGenerations(generations) => {
+                (history.len() >= *generations, StopReason::MaxGenerations)
+            }
+
+            Self::TargetFitness(fitness) => {
+                let reached = latest.map_or(false, |latest| latest.max_fitness() >= *fitness);
+
+                (reached, StopReason::TargetFitness)
+            }
+
+            Self::Plateau { generations, tolerance } => {
+                let plateaued = history.len() > *generations && {
+                    let (before, recent) = history.split_at(history.len() - generations);
+
+                    best(recent) - best(before) <= *tolerance
+                };
+
+                (plateaued, StopReason::Plateau)
+            }
+
+            Self::TimeBudget(budget) => {
+                (elapsed >= *budget, StopReason::TimeBudget)
+            }
+
+            Self::FitnessSpreadCollapse { min_std_dev } => {
+                let collapsed = latest
+                    .map_or(false, |latest| latest.std_dev_fitness() < *min_std_dev);
+
+                (collapsed, StopReason::FitnessSpreadCollapse)
+            }
+
+            Self::Any(conditions) => {
+                return conditions
+                    .iter()
+                    .flat_map(|condition| condition.check(history, elapsed))
+                    .collect();
+            }
+
+            Self::All(conditions) => {
+                let reasons: Vec<_> = conditions
+                    .iter()
+                    .map(|condition| condition.check(history, elapsed))
+                    .collect();
+
+                if reasons.is_empty() || reasons.iter().any(Vec::is_empty) {
+                    return Vec::new();
+                }
+
+                return reasons.into_iter().flatten().collect();
+            }
+        };
+
+        if met {
+            vec![reason]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Best fitness found within `history`.
+fn best(history: &[Statistics]) -> f32 {
+    history
+        .iter()
+        .map(Statistics::max_fitness)
+        .fold(f32::NEG_INFINITY, f32::max)
+}
+
+/// What [`Optimizer::run()`](crate::optimizer::Optimizer::run) ended up
+/// with.
+#[derive(Clone, Debug)]
+pub struct Outcome<I> {
+    population: Vec<I>,
+    history: Vec<Statistics>,
+    stop_reasons: Vec<StopReason>,
+}
+
+impl<I> Outcome<I> {
+    pub(crate) fn new(
+        population: Vec<I>,
+        history: Vec<Statistics>,
+        stop_reasons: Vec<StopReason>,
+    ) -> Self {
+        Self {
+            population,
+            history,
+            stop_reasons,
+        }
+    }
+
+    /// Population the run has ended up with, i.e. the offspring of the
+    /// generation described by the last entry of `history()` (or the
+    /// initial population, if nothing has been evolved).
+    pub fn population(&self) -> &[I] {
+        &self.population
+    }
+
+    pub fn into_population(self) -> Vec<I> {
+        self.population
+    }
+
+    /// How many generations have been evolved.
+    pub fn generations(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Statistics of each evolved generation, oldest first.
+    pub fn history(&self) -> &[Statistics] {
+        &self.history
+    }
+
+    /// Basic conditions that have caused the run to stop.
+    pub fn stop_reasons(&self) -> &[StopReason] {
+        &self.stop_reasons
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns statistics of generations with given best fitnesses, each
+    /// one with a standard deviation of 1.0.
+    fn history(best: &[f32]) -> Vec<Statistics> {
+        best.iter()
+            .map(|&fitness| {
+                Statistics::new(vec![fitness, fitness - 2.0])
+            })
+            .collect()
+    }
+
+    fn check(condition: StopCondition, best: &[f32]) -> Vec<StopReason> {
+        condition.check(&history(best), Duration::from_secs(1))
+    }
+
+    #[test]
+    fn max_generations() {
+        let condition = StopCondition::MaxGenerations(3);
+
+        assert_eq!(check(condition.clone(), &[1.0, 2.0]), vec![]);
+        assert_eq!(check(condition, &[1.0, 2.0, 3.0]), vec![StopReason::MaxGenerations]);
+    }
+
+    #[test]
+    fn target_fitness() {
+        let condition = StopCondition::TargetFitness(5.0);
+
+        assert_eq!(check(condition.clone(), &[4.0]), vec![]);
+        assert_eq!(check(condition, &[4.0, 5.0]), vec![StopReason::TargetFitness]);
+    }
+
+    #[test]
+    fn plateau() {
+        let condition = StopCondition::Plateau {
+            generations: 2,
+            tolerance: 0.5,
+        };
+
+        assert_eq!(check(condition.clone(), &[1.0, 1.0]), vec![]);
+        assert_eq!(check(condition.clone(), &[1.0, 1.2, 2.0]), vec![]);
+        assert_eq!(check(condition, &[1.0, 1.2, 1.4]), vec![StopReason::Plateau]);
+
+        let condition = StopCondition::Plateau {
+            generations: 0,
+            tolerance: 0.5,
+        };
+
+        assert_eq!(check(condition, &[1.0]), vec![StopReason::Plateau]);
+    }
+
+    #[test]
+    fn time_budget() {
+        let condition = StopCondition::TimeBudget(Duration::from_secs(1));
+        let history = history(&[1.0]);
+
+        assert_eq!(condition.check(&history, Duration::from_millis(999)), vec![]);
+        assert_eq!(condition.check(&history, Duration::from_secs(1)), vec![StopReason::TimeBudget]);
+    }
+
+    #[test]
+    fn fitness_spread_collapse() {
+        let condition = StopCondition::FitnessSpreadCollapse { min_std_dev: 1.5 };
+
+        assert_eq!(check(condition, &[1.0]), vec![StopReason::FitnessSpreadCollapse]);
+    }
+
+    #[test]
+    fn any() {
+        let condition = StopCondition::Any(vec![
+            StopCondition::MaxGenerations(3),
+            StopCondition::TargetFitness(5.0),
+        ]);
+
+        assert_eq!(check(condition.clone(), &[1.0]), vec![]);
+        assert_eq!(check(condition.clone(), &[5.0]), vec![StopReason::TargetFitness]);
+
+        assert_eq!(
+            check(condition, &[1.0, 1.0, 5.0]),
+            vec![StopReason::MaxGenerations, StopReason::TargetFitness],
+        );
+    }
+
+    #[test]
+    fn all() {
+        let condition = StopCondition::All(vec![
+            StopCondition::MaxGenerations(2),
+            StopCondition::TargetFitness(5.0),
+        ]);
+
+        assert_eq!(check(condition.clone(), &[5.0]), vec![]);
+        assert_eq!(check(condition.clone(), &[1.0, 1.0]), vec![]);
+
+        assert_eq!(
+            check(condition, &[1.0, 5.0]),
+            vec![StopReason::MaxGenerations, StopReason::TargetFitness],
+        );
+    }
+
+    #[test]
+    fn has_empty_group() {
+        assert!(StopCondition::Any(vec![]).has_empty_group());
+        assert!(StopCondition::All(vec![]).has_empty_group());
+
+        assert!(StopCondition::Any(vec![
+            StopCondition::MaxGenerations(10),
+            StopCondition::All(vec![]),
+        ])
+        .has_empty_group());
+
+        assert!(!StopCondition::Any(vec![StopCondition::MaxGenerations(10)]).has_empty_group());
+        assert!(!StopCondition::MaxGenerations(10).has_empty_group());
+    }
+
+    #[test]
+    fn uses_clock() {
+        assert!(StopCondition::TimeBudget(Duration::from_secs(1)).uses_clock());
+
+        assert!(StopCondition::Any(vec![
+            StopCondition::MaxGenerations(10),
+            StopCondition::All(vec![StopCondition::TimeBudget(Duration::from_secs(1))]),
+        ])
+        .uses_clock());
+
+        assert!(!StopCondition::Any(vec![
+            StopCondition::MaxGenerations(10),
+            StopCondition::TargetFitness(1.0),
+        ])
+        .uses_clock());
+    }
+
+    #[test]
+    fn given_empty_history() {
+        let check = |condition: StopCondition| condition.check(&[], Duration::from_secs(1));
+
+        assert_eq!(check(StopCondition::MaxGenerations(0)), vec![StopReason::MaxGenerations]);
+        assert_eq!(check(StopCondition::MaxGenerations(1)), vec![]);
+        assert_eq!(check(StopCondition::TargetFitness(f32::NEG_INFINITY)), vec![]);
+        assert_eq!(check(StopCondition::Plateau { generations: 0, tolerance: 1.0 }), vec![]);
+
+        assert_eq!(
+            check(StopCondition::TimeBudget(Duration::from_secs(1))),
+            vec![StopReason::TimeBudget],
+        );
+    }
+}
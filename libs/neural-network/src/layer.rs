@@ -19,6 +19,18 @@ impl Layer {
         Self { neurons }
     }
 
+    pub fn from_weights(
+        input_neurons: usize,
+        output_neurons: usize,
+        weights: &mut dyn Iterator<Item = f32>,
+    ) -> Self {
+        let neurons = (0..output_neurons)
+            .map(|_| Neuron::from_weights(input_neurons, weights))
+            .collect();
+
+        Self { neurons }
+    }
+
     pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
         self.neurons
             .iter()
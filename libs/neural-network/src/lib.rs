@@ -2,6 +2,7 @@ pub use self::layer_topology::LayerTopology;
 
 use self::layer::Layer;
 use rand::RngCore;
+use std::iter::once;
 
 mod layer;
 mod layer_topology;
@@ -28,11 +29,39 @@ impl Network {
         Self { layers }
     }
 
+    /// Rebuilds a network from weights returned by [`Network::weights()`].
+    pub fn from_weights(layers: &[LayerTopology], weights: impl IntoIterator<Item = f32>) -> Self {
+        assert!(layers.len() > 1);
+
+        let mut weights = weights.into_iter();
+
+        let layers = layers
+            .windows(2)
+            .map(|layers| Layer::from_weights(layers[0].neurons, layers[1].neurons, &mut weights))
+            .collect();
+
+        if weights.next().is_some() {
+            panic!("got too many weights");
+        }
+
+        Self { layers }
+    }
+
     pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
         self.layers
             .iter()
             .fold(inputs, |inputs, layer| layer.propagate(inputs))
     }
+
+    /// Returns all the network's parameters, neuron by neuron, each
+    /// neuron's bias followed by its weights.
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.neurons.iter())
+            .flat_map(|neuron| once(&neuron.bias).chain(&neuron.weights))
+            .copied()
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +92,58 @@ mod tests {
         }
     }
 
+    mod weights {
+        use super::*;
+        use crate::neuron::Neuron;
+
+        #[test]
+        fn test() {
+            let network = Network::new(vec![
+                Layer::new(vec![Neuron::new(0.1, vec![0.2, 0.3, 0.4])]),
+                Layer::new(vec![Neuron::new(0.5, vec![0.6])]),
+            ]);
+
+            let actual: Vec<_> = network.weights().collect();
+            let expected = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+
+            approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+        }
+    }
+
+    mod from_weights {
+        use super::*;
+
+        fn layers() -> [LayerTopology; 3] {
+            [
+                LayerTopology { neurons: 3 },
+                LayerTopology { neurons: 2 },
+                LayerTopology { neurons: 1 },
+            ]
+        }
+
+        #[test]
+        fn test() {
+            let weights: Vec<f32> = (0..11).map(|n| n as f32 / 10.0).collect();
+
+            let network = Network::from_weights(&layers(), weights.clone());
+            let actual: Vec<_> = network.weights().collect();
+
+            approx::assert_relative_eq!(actual.as_slice(), weights.as_slice());
+        }
+
+        #[test]
+        #[should_panic(expected = "got too many weights")]
+        fn panics_on_too_many_weights() {
+            Network::from_weights(&layers(), vec![0.0; 12]);
+        }
+
+        #[test]
+        #[should_panic(expected = "got not enough weights")]
+        fn panics_on_not_enough_weights() {
+            Network::from_weights(&layers(), vec![0.0; 10]);
+        }
+    }
+
     mod propagate {
         use super::*;
 
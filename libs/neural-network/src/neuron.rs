@@ -23,6 +23,16 @@ impl Neuron {
         Self { bias, weights }
     }
 
+    pub fn from_weights(output_size: usize, weights: &mut dyn Iterator<Item = f32>) -> Self {
+        let bias = weights.next().expect("got not enough weights");
+
+        let weights = (0..output_size)
+            .map(|_| weights.next().expect("got not enough weights"))
+            .collect();
+
+        Self { bias, weights }
+    }
+
     pub fn propagate(&self, inputs: &[f32]) -> f32 {
         let output = inputs
             .iter()
@@ -56,6 +66,26 @@ mod tests {
         }
     }
 
+    mod from_weights {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut weights = vec![0.5, -0.3, 0.8].into_iter();
+            let neuron = Neuron::from_weights(2, &mut weights);
+
+            approx::assert_relative_eq!(neuron.bias, 0.5);
+            approx::assert_relative_eq!(neuron.weights.as_slice(), [-0.3, 0.8].as_ref());
+            assert!(weights.next().is_none());
+        }
+
+        #[test]
+        #[should_panic(expected = "got not enough weights")]
+        fn panics_when_running_out_of_weights() {
+            Neuron::from_weights(2, &mut vec![0.5, -0.3].into_iter());
+        }
+    }
+
     mod propagate {
         use super::*;
 
@@ -1,33 +1,112 @@
 use lib_simulation as sim;
 use wasm_bindgen::prelude::*;
 use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
 use serde::Serialize;
 
 #[wasm_bindgen]
 pub struct Simulation {
-    rng: ThreadRng,
+    rng: ChaCha8Rng,
+    seed: u64,
     sim: sim::Simulation,
 }
 
 #[wasm_bindgen]
 impl Simulation {
+    /// Creates a simulation; `config` can be left undefined to go with
+    /// the defaults, or contain just the fields that should differ from
+    /// them.
+    ///
+    /// Passing the same `seed` and `config` replays exactly the same
+    /// simulation; without a seed, a random one is picked (and can be
+    /// read back through `seed()`).
+    ///
+    /// Throws if `config` can't be deserialized or contains invalid
+    /// values.
     #[wasm_bindgen(constructor)]
-    pub fn new() -> Self {
-        let mut rng = thread_rng();
-        let sim = sim::Simulation::random(&mut rng);
+    pub fn new(config: JsValue, seed: Option<u64>) -> Result<Simulation, JsValue> {
+        let config: sim::Config = if config.is_undefined() || config.is_null() {
+            Default::default()
+        } else {
+            serde_wasm_bindgen::from_value(config)?
+        };
 
-        Self { rng, sim }
+        config.validate().map_err(|problem| JsValue::from_str(&problem))?;
+
+        let seed = seed.unwrap_or_else(|| thread_rng().gen());
+        let (sim, rng) = sim::Simulation::from_seed(config, seed);
+
+        Ok(Self { rng, seed, sim })
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn default_config() -> JsValue {
+        serde_wasm_bindgen::to_value(&sim::Config::default()).unwrap()
+    }
+
+    pub fn config(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(self.sim.config()).unwrap()
     }
 
     pub fn world(&self) -> JsValue {
         let world = World::from(self.sim.world());
-        JsValue::from_serde(&world).unwrap()
+        serde_wasm_bindgen::to_value(&world).unwrap()
+    }
+
+    /// Advances the simulation by a single tick; returns statistics
+    /// of the generation that has just ended (if any), or `undefined`.
+    pub fn step(&mut self) -> JsValue {
+        match self.sim.step(&mut self.rng) {
+            Some(statistics) => self.statistics(&statistics),
+            None => JsValue::UNDEFINED,
+        }
+    }
+
+    /// Fast-forwards until the end of the current generation, returning
+    /// its statistics.
+    pub fn train(&mut self) -> JsValue {
+        let statistics = self.sim.train(&mut self.rng);
+        self.statistics(&statistics)
+    }
+
+    fn statistics(&self, statistics: &sim::Statistics) -> JsValue {
+        let statistics = Statistics::new(self.sim.generation(), statistics);
+        serde_wasm_bindgen::to_value(&statistics).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Statistics {
+    /// Number of the generation these statistics describe, counting
+    /// from zero
+    pub generation: usize,
+    pub min_fitness: f32,
+    pub max_fitness: f32,
+    pub mean_fitness: f32,
+    pub median_fitness: f32,
+    pub std_dev_fitness: f32,
+}
+
+impl Statistics {
+    fn new(evolved_generations: usize, statistics: &sim::Statistics) -> Self {
+        Self {
+            generation: evolved_generations - 1,
+            min_fitness: statistics.min_fitness(),
+            max_fitness: statistics.max_fitness(),
+            mean_fitness: statistics.mean_fitness(),
+            median_fitness: statistics.median_fitness(),
+            std_dev_fitness: statistics.std_dev_fitness(),
+        }
     }
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct World {
     pub animals: Vec<Animal>,
+    pub food: Vec<Food>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -35,6 +114,13 @@ pub struct Animal {
     pub x: f32,
     pub y: f32,
     pub rotation: f32,
+    pub satiation: usize,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Food {
+    pub x: f32,
+    pub y: f32,
 }
 
 impl From<&sim::World> for World {
@@ -44,9 +130,13 @@ impl From<&sim::World> for World {
             .iter()
             .map(Animal::from)
             .collect();
+        let food = world
+            .food()
+            .iter()
+            .map(Food::from)
+            .collect();
 
-        Self { animals }
-
+        Self { animals, food }
     }
 }
 
@@ -56,6 +146,16 @@ impl From<&sim::Animal> for Animal {
             x: animal.position().x,
             y: animal.position().y,
             rotation: animal.rotation().angle(),
+            satiation: animal.satiation(),
+        }
+    }
+}
+
+impl From<&sim::Food> for Food {
+    fn from(food: &sim::Food) -> Self {
+        Self {
+            x: food.position().x,
+            y: food.position().y,
         }
     }
 }
@@ -0,0 +1,77 @@
+use crate::*;
+
+#[derive(Debug)]
+pub struct Animal {
+    pub(crate) position: nalgebra::Point2<f32>,
+    pub(crate) rotation: nalgebra::Rotation2<f32>,
+    pub(crate) speed: f32,
+    pub(crate) eye: Eye,
+    pub(crate) brain: Brain,
+    /// Number of food items eaten so far
+    pub(crate) satiation: usize,
+}
+
+impl Animal {
+    pub fn random(config: &Config, rng: &mut dyn rand::RngCore) -> Self {
+        let brain = Brain::random(config, rng);
+
+        Self::new(config, brain, rng)
+    }
+
+    pub(crate) fn from_chromosome(
+        config: &Config,
+        chromosome: ga::chromosome::Chromosome,
+        rng: &mut dyn rand::RngCore,
+    ) -> Self {
+        let brain = Brain::from_chromosome(config, chromosome);
+
+        Self::new(config, brain, rng)
+    }
+
+    pub(crate) fn as_chromosome(&self) -> ga::chromosome::Chromosome {
+        self.brain.as_chromosome()
+    }
+
+    fn new(config: &Config, brain: Brain, rng: &mut dyn rand::RngCore) -> Self {
+        use rand::Rng;
+
+        let eye = Eye::new(
+            config.eye_fov_range,
+            config.eye_fov_angle,
+            config.eye_cells,
+        );
+
+        Self {
+            position: rng.gen(),
+            rotation: rng.gen(),
+            speed: (config.sim_speed_min + config.sim_speed_max) / 2.0,
+            eye,
+            brain,
+            satiation: 0,
+        }
+    }
+
+    pub fn position(&self) -> nalgebra::Point2<f32> {
+        self.position
+    }
+
+    pub fn rotation(&self) -> nalgebra::Rotation2<f32> {
+        self.rotation
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn eye(&self) -> &Eye {
+        &self.eye
+    }
+
+    pub fn brain(&self) -> &Brain {
+        &self.brain
+    }
+
+    pub fn satiation(&self) -> usize {
+        self.satiation
+    }
+}
@@ -0,0 +1,39 @@
+use crate::*;
+
+/// Adapts an [`Animal`] to what [`ga::GeneticAlgorithm`] expects.
+pub struct AnimalIndividual {
+    fitness: f32,
+    chromosome: ga::chromosome::Chromosome,
+}
+
+impl AnimalIndividual {
+    pub fn from_animal(animal: &Animal) -> Self {
+        Self {
+            fitness: animal.satiation as f32,
+            chromosome: animal.as_chromosome(),
+        }
+    }
+
+    pub fn into_animal(self, config: &Config, rng: &mut dyn rand::RngCore) -> Animal {
+        Animal::from_chromosome(config, self.chromosome, rng)
+    }
+}
+
+impl ga::individual::Individual for AnimalIndividual {
+    type Gene = f32;
+
+    fn create(chromosome: ga::chromosome::Chromosome) -> Self {
+        Self {
+            fitness: 0.0,
+            chromosome,
+        }
+    }
+
+    fn chromosome(&self) -> &ga::chromosome::Chromosome {
+        &self.chromosome
+    }
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+}
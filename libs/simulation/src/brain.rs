@@ -0,0 +1,115 @@
+use crate::*;
+use lib_neural_network as nn;
+
+#[derive(Debug)]
+pub struct Brain {
+    nn: nn::Network,
+}
+
+impl Brain {
+    pub fn random(config: &Config, rng: &mut dyn rand::RngCore) -> Self {
+        Self {
+            nn: nn::Network::random(&Self::topology(config), rng),
+        }
+    }
+
+    pub(crate) fn from_chromosome(
+        config: &Config,
+        chromosome: ga::chromosome::Chromosome,
+    ) -> Self {
+        Self {
+            nn: nn::Network::from_weights(&Self::topology(config), chromosome),
+        }
+    }
+
+    pub(crate) fn as_chromosome(&self) -> ga::chromosome::Chromosome {
+        self.nn.weights().collect()
+    }
+
+    /// Turns what the animal sees into `(speed, rotation)` changes it'd
+    /// like to make, each limited by the configured acceleration.
+    pub(crate) fn propagate(&self, config: &Config, vision: Vec<f32>) -> (f32, f32) {
+        let response = self.nn.propagate(vision);
+
+        // Our neurons use ReLU, so outputs are never negative; shifting
+        // them lets animals slow down and turn both ways too
+        let r0 = response[0].clamp(0.0, 1.0) - 0.5;
+        let r1 = response[1].clamp(0.0, 1.0) - 0.5;
+
+        let speed = (r0 + r1).clamp(-config.sim_speed_accel, config.sim_speed_accel);
+        let rotation = (r0 - r1).clamp(-config.sim_rotation_accel, config.sim_rotation_accel);
+
+        (speed, rotation)
+    }
+
+    fn topology(config: &Config) -> Vec<nn::LayerTopology> {
+        // Input layer, fed with the eye's vision
+        let input = nn::LayerTopology {
+            neurons: config.eye_cells,
+        };
+
+        let hidden = config
+            .brain_hidden_layers
+            .iter()
+            .map(|&neurons| nn::LayerTopology { neurons });
+
+        // Output layer, returning speed and rotation
+        let output = nn::LayerTopology { neurons: 2 };
+
+        std::iter::once(input)
+            .chain(hidden)
+            .chain(std::iter::once(output))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn survives_a_trip_through_chromosome() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let config = Config::default();
+        let brain = Brain::random(&config, &mut rng);
+
+        let chromosome = brain.as_chromosome();
+        let restored = Brain::from_chromosome(&config, chromosome.clone());
+
+        let expected: Vec<_> = chromosome.into_iter().collect();
+        let actual: Vec<_> = restored.as_chromosome().into_iter().collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn propagate_stays_within_limits() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let config = Config::default();
+
+        for _ in 0..100 {
+            let brain = Brain::random(&config, &mut rng);
+            let (speed, rotation) = brain.propagate(&config, vec![1.0; config.eye_cells]);
+
+            assert!((-config.sim_speed_accel..=config.sim_speed_accel).contains(&speed));
+            assert!((-config.sim_rotation_accel..=config.sim_rotation_accel).contains(&rotation));
+        }
+    }
+
+    #[test]
+    fn follows_configured_hidden_layers() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let config = Config {
+            eye_cells: 3,
+            brain_hidden_layers: vec![4, 5],
+            ..Default::default()
+        };
+
+        let brain = Brain::random(&config, &mut rng);
+
+        // (3 weights + bias) * 4 + (4 weights + bias) * 5 + (5 weights + bias) * 2
+        assert_eq!(brain.as_chromosome().len(), 16 + 25 + 12);
+    }
+}
@@ -0,0 +1,324 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+/// Tunables of a [`Simulation`].
+///
+/// Fields missing during deserialization fall back to their defaults,
+/// so a config file only has to mention the values it changes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// How many animals live in the world at once.
+    pub world_animals: usize,
+
+    /// How many food items lie around the world at once.
+    pub world_foods: usize,
+
+    /// What happens to animals reaching the edge of the world (can be
+    /// changed later through [`World::set_topology()`]).
+    pub world_topology: Topology,
+
+    /// How close an animal has to get to a food item to eat it (can be
+    /// changed later through [`World::set_food_radius()`]).
+    pub food_radius: f32,
+
+    /// Minimum speed of an animal.
+    ///
+    /// Keeping it above zero prevents animals from getting stuck in one
+    /// place.
+    pub sim_speed_min: f32,
+
+    /// Maximum speed of an animal.
+    ///
+    /// Keeping it "sane" prevents animals from accelerating up to
+    /// infinity, which makes the simulation unrealistic :-)
+    pub sim_speed_max: f32,
+
+    /// Speed acceleration; determines how much the brain can affect
+    /// animal's speed during one step.
+    pub sim_speed_accel: f32,
+
+    /// Rotation acceleration; determines how much the brain can affect
+    /// animal's rotation during one step.
+    pub sim_rotation_accel: f32,
+
+    /// How many steps each generation lasts (can be changed later
+    /// through [`Simulation::set_generation_length()`]).
+    pub sim_generation_length: usize,
+
+    /// How far our eye can see:
+    ///
+    /// -----------------
+    /// |               |
+    /// |               |
+    /// |               |
+    /// |@      %      %|
+    /// |               |
+    /// |               |
+    /// |               |
+    /// -----------------
+    ///
+    /// If @ marks our animal and % marks food, then a range of:
+    ///
+    /// - 0.1 = 10% of the map = animal sees no foods (at least in this case)
+    /// - 0.5 = 50% of the map = animal sees one of the foods
+    /// - 1.0 = 100% of the map = animal sees both foods
+    pub eye_fov_range: f32,
+
+    /// How wide our eye can see, centered around the animal's rotation.
+    pub eye_fov_angle: f32,
+
+    /// How much photoreceptors there are in a single eye.
+    ///
+    /// More cells mean our animals will have more "crisp" vision,
+    /// allowing them to locate the food more precisely - but the
+    /// trade-off is that the evolution process will then take longer, or
+    /// even fail, unable to find any solution.
+    pub eye_cells: usize,
+
+    /// Sizes of the brain's hidden layers, sitting between the eye's
+    /// cells and the two output neurons.
+    pub brain_hidden_layers: Vec<usize>,
+
+    /// Algorithm evolving the animals' brains.
+    pub ga_optimizer: OptimizerKind,
+
+    /// Probability of changing a gene during mutation (ignored by
+    /// [`OptimizerKind::CmaEs`]).
+    pub ga_mutation_chance: f32,
+
+    /// Magnitude of the changes applied during mutation (ignored by
+    /// [`OptimizerKind::CmaEs`]).
+    pub ga_mutation_coeff: f32,
+
+    /// How many of the best animals are passed to the next generation
+    /// as they are, without crossover and mutation (ignored by
+    /// [`OptimizerKind::CmaEs`]).
+    pub ga_elitism: usize,
+
+    /// Initial step size of CMA-ES, i.e. how far from the initial brains
+    /// the first samples are drawn (used by [`OptimizerKind::CmaEs`] only).
+    pub ga_cma_sigma: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            world_animals: 40,
+            world_foods: 60,
+            world_topology: Topology::default(),
+            food_radius: 0.01,
+            sim_speed_min: 0.001,
+            sim_speed_max: 0.005,
+            sim_speed_accel: 0.2,
+            sim_rotation_accel: FRAC_PI_2,
+            sim_generation_length: 2500,
+            eye_fov_range: 0.25,
+            eye_fov_angle: PI + FRAC_PI_4,
+            eye_cells: 9,
+            brain_hidden_layers: vec![18],
+            ga_optimizer: OptimizerKind::default(),
+            ga_mutation_chance: 0.01,
+            ga_mutation_coeff: 0.3,
+            ga_elitism: 0,
+            ga_cma_sigma: 0.3,
+        }
+    }
+}
+
+impl Config {
+    /// Checks whether this config describes a simulation that can be
+    /// created and run, returning a description of the first problem
+    /// found otherwise.
+    pub fn validate(&self) -> Result<(), String> {
+        fn check(condition: bool, problem: &str) -> Result<(), String> {
+            if condition {
+                Ok(())
+            } else {
+                Err(problem.to_string())
+            }
+        }
+
+        check(self.world_animals > 0, "world_animals must be greater than zero")?;
+
+        check(
+            self.food_radius >= 0.0 && self.food_radius.is_finite(),
+            "food_radius must be a non-negative number",
+        )?;
+
+        check(
+            self.sim_speed_min >= 0.0 && self.sim_speed_min.is_finite(),
+            "sim_speed_min must be a non-negative number",
+        )?;
+
+        check(
+            self.sim_speed_max.is_finite(),
+            "sim_speed_max must be a number",
+        )?;
+
+        check(
+            self.sim_speed_min <= self.sim_speed_max,
+            "sim_speed_min must not be greater than sim_speed_max",
+        )?;
+
+        check(
+            self.sim_speed_accel >= 0.0 && self.sim_speed_accel.is_finite(),
+            "sim_speed_accel must be a non-negative number",
+        )?;
+
+        check(
+            self.sim_rotation_accel >= 0.0 && self.sim_rotation_accel.is_finite(),
+            "sim_rotation_accel must be a non-negative number",
+        )?;
+
+        check(
+            self.sim_generation_length > 0,
+            "sim_generation_length must be greater than zero",
+        )?;
+
+        check(
+            self.eye_fov_range > 0.0 && self.eye_fov_range.is_finite(),
+            "eye_fov_range must be greater than zero",
+        )?;
+
+        check(
+            self.eye_fov_angle > 0.0 && self.eye_fov_angle.is_finite(),
+            "eye_fov_angle must be greater than zero",
+        )?;
+
+        check(self.eye_cells > 0, "eye_cells must be greater than zero")?;
+
+        check(
+            self.brain_hidden_layers.iter().all(|&neurons| neurons > 0),
+            "brain_hidden_layers must not contain empty layers",
+        )?;
+
+        check(
+            (0.0..=1.0).contains(&self.ga_mutation_chance),
+            "ga_mutation_chance must be within 0.0..=1.0",
+        )?;
+
+        check(
+            self.ga_mutation_coeff >= 0.0 && self.ga_mutation_coeff.is_finite(),
+            "ga_mutation_coeff must be a non-negative number",
+        )?;
+
+        check(
+            self.ga_elitism <= self.world_animals,
+            "ga_elitism must not be greater than world_animals",
+        )?;
+
+        check(
+            self.ga_cma_sigma > 0.0 && self.ga_cma_sigma.is_finite(),
+            "ga_cma_sigma must be greater than zero",
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_from_json() {
+        let config: Config = serde_json::from_str(
+            r#"{
+                "world_animals": 10,
+                "world_topology": "reflect",
+                "brain_hidden_layers": [4, 4]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                world_animals: 10,
+                world_topology: Topology::Reflect,
+                brain_hidden_layers: vec![4, 4],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn loads_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+                world_foods = 100
+                world_topology = "clamp"
+                eye_cells = 5
+                ga_optimizer = "cma_es"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                world_foods: 100,
+                world_topology: Topology::Clamp,
+                eye_cells: 5,
+                ga_optimizer: OptimizerKind::CmaEs,
+                ..Default::default()
+            }
+        );
+    }
+
+    mod validate {
+        use super::*;
+
+        fn problem(config: Config) -> String {
+            config.validate().unwrap_err()
+        }
+
+        #[test]
+        fn accepts_default_config() {
+            assert_eq!(Config::default().validate(), Ok(()));
+        }
+
+        #[test]
+        fn rejects_out_of_range_values() {
+            assert!(problem(Config { world_animals: 0, ..Default::default() })
+                .starts_with("world_animals"));
+
+            assert!(problem(Config { sim_generation_length: 0, ..Default::default() })
+                .starts_with("sim_generation_length"));
+
+            assert!(problem(Config { eye_cells: 0, ..Default::default() })
+                .starts_with("eye_cells"));
+
+            assert!(problem(Config { brain_hidden_layers: vec![4, 0], ..Default::default() })
+                .starts_with("brain_hidden_layers"));
+
+            assert!(problem(Config { ga_mutation_chance: 2.0, ..Default::default() })
+                .starts_with("ga_mutation_chance"));
+
+            assert!(problem(Config { ga_cma_sigma: 0.0, ..Default::default() })
+                .starts_with("ga_cma_sigma"));
+
+            assert!(problem(Config { food_radius: f32::NAN, ..Default::default() })
+                .starts_with("food_radius"));
+        }
+
+        #[test]
+        fn rejects_inconsistent_values() {
+            assert!(problem(Config { sim_speed_min: 0.5, sim_speed_max: 0.1, ..Default::default() })
+                .starts_with("sim_speed_min"));
+
+            assert!(problem(Config { world_animals: 2, ga_elitism: 3, ..Default::default() })
+                .starts_with("ga_elitism"));
+        }
+    }
+
+    #[test]
+    fn survives_a_round_trip() {
+        let config = Config::default();
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+}
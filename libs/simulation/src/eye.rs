@@ -0,0 +1,158 @@
+use crate::*;
+use std::f32::consts::PI;
+
+#[derive(Clone, Debug)]
+pub struct Eye {
+    fov_range: f32,
+    fov_angle: f32,
+    cells: usize,
+}
+
+impl Eye {
+    pub fn new(fov_range: f32, fov_angle: f32, cells: usize) -> Self {
+        assert!(fov_range > 0.0);
+        assert!(fov_angle > 0.0);
+        assert!(cells > 0);
+
+        Self {
+            fov_range,
+            fov_angle,
+            cells,
+        }
+    }
+
+    pub fn cells(&self) -> usize {
+        self.cells
+    }
+
+    /// Returns how strongly each of the eye's cells is stimulated by the
+    /// food in sight; cells are ordered by increasing angle, and food
+    /// closer to the animal stimulates its cell more.
+    pub fn process_vision(
+        &self,
+        position: nalgebra::Point2<f32>,
+        rotation: nalgebra::Rotation2<f32>,
+        foods: &[Food],
+    ) -> Vec<f32> {
+        let mut cells = vec![0.0; self.cells];
+
+        for food in foods {
+            let vec = food.position - position;
+            let dist = vec.norm();
+
+            if dist >= self.fov_range {
+                continue;
+            }
+
+            // Angle between the animal's heading and the food, wrapped
+            // into `-PI..=PI`, so that food straight ahead yields zero
+            let angle = vec.y.atan2(vec.x) - rotation.angle();
+            let angle = (angle + PI).rem_euclid(2.0 * PI) - PI;
+
+            if angle < -self.fov_angle / 2.0 || angle > self.fov_angle / 2.0 {
+                continue;
+            }
+
+            // Transforms the angle from `-FOV_ANGLE/2..=FOV_ANGLE/2` into
+            // `0.0..=1.0`, and then into the cell's index
+            let cell = (angle + self.fov_angle / 2.0) / self.fov_angle;
+            let cell = cell * (self.cells as f32);
+            let cell = (cell as usize).min(cells.len() - 1);
+
+            // Food at the edge of our sight stimulates the cell barely,
+            // food right in front of our nose - fully
+            let energy = (self.fov_range - dist) / self.fov_range;
+
+            cells[cell] += energy;
+        }
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn food(x: f32, y: f32) -> Food {
+        Food {
+            position: nalgebra::Point2::new(x, y),
+        }
+    }
+
+    fn process_vision(eye: &Eye, rotation: f32, foods: &[Food]) -> Vec<f32> {
+        eye.process_vision(
+            nalgebra::Point2::new(0.5, 0.5),
+            nalgebra::Rotation2::new(rotation),
+            foods,
+        )
+    }
+
+    #[test]
+    fn sees_nothing_without_food() {
+        let eye = Eye::new(0.25, PI + FRAC_PI_4, 9);
+        let actual = process_vision(&eye, 0.0, &[]);
+
+        assert_eq!(actual, vec![0.0; 9]);
+    }
+
+    #[test]
+    fn sees_food_straight_ahead_in_the_middle_cell() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 3);
+        let actual = process_vision(&eye, 0.0, &[food(0.75, 0.5)]);
+
+        approx::assert_relative_eq!(actual.as_slice(), [0.0, 0.75, 0.0].as_ref());
+    }
+
+    #[test]
+    fn sees_food_on_either_side_in_the_outer_cells() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 3);
+        let actual = process_vision(&eye, 0.0, &[food(0.75, 0.4), food(0.75, 0.6)]);
+
+        assert!(actual[0] > 0.0);
+        assert_eq!(actual[1], 0.0);
+        assert!(actual[2] > 0.0);
+    }
+
+    #[test]
+    fn follows_the_animal_rotation() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 3);
+        let actual = process_vision(&eye, FRAC_PI_2, &[food(0.5, 0.75)]);
+
+        approx::assert_relative_eq!(actual.as_slice(), [0.0, 0.75, 0.0].as_ref());
+    }
+
+    #[test]
+    fn ignores_food_outside_of_fov_angle() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 3);
+        let actual = process_vision(&eye, 0.0, &[food(0.25, 0.5), food(0.5, 0.75)]);
+
+        assert_eq!(actual, vec![0.0; 3]);
+    }
+
+    #[test]
+    fn ignores_food_outside_of_fov_range() {
+        let eye = Eye::new(0.2, FRAC_PI_2, 3);
+        let actual = process_vision(&eye, 0.0, &[food(0.75, 0.5)]);
+
+        assert_eq!(actual, vec![0.0; 3]);
+    }
+
+    #[test]
+    fn closer_food_stimulates_more() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 1);
+        let near = process_vision(&eye, 0.0, &[food(0.6, 0.5)]);
+        let far = process_vision(&eye, 0.0, &[food(0.9, 0.5)]);
+
+        assert!(near[0] > far[0]);
+    }
+
+    #[test]
+    fn accumulates_food_within_the_same_cell() {
+        let eye = Eye::new(1.0, FRAC_PI_2, 1);
+        let actual = process_vision(&eye, 0.0, &[food(0.75, 0.5), food(0.75, 0.5)]);
+
+        approx::assert_relative_eq!(actual.as_slice(), [1.5].as_ref());
+    }
+}
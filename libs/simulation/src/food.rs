@@ -0,0 +1,17 @@
+#[derive(Debug)]
+pub struct Food {
+    pub(crate) position: nalgebra::Point2<f32>,
+}
+
+impl Food {
+    pub fn random(rng: &mut dyn rand::RngCore) -> Self {
+        use rand::Rng;
+        Self {
+            position: rng.gen(),
+        }
+    }
+
+    pub fn position(&self) -> nalgebra::Point2<f32> {
+        self.position
+    }
+}
@@ -1,97 +1,577 @@
-use nalgebra;
+mod animal;
+mod animal_individual;
+mod brain;
+mod config;
+mod eye;
+mod food;
+mod optimizer_kind;
+mod topology;
+mod world;
+
+pub use self::{
+    animal::*,
+    animal_individual::*,
+    brain::*,
+    config::*,
+    eye::*,
+    food::*,
+    optimizer_kind::*,
+    topology::*,
+    world::*,
+};
+pub use lib_genetic_algorithm::statistics::Statistics;
+
+use ga::optimizer::Optimizer;
+use lib_genetic_algorithm as ga;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 pub struct Simulation {
+    config: Config,
     world: World,
+    optimizer: Box<dyn Optimizer<AnimalIndividual>>,
+    age: usize,
+    generation: usize,
 }
 
 impl Simulation {
     pub fn random(rng: &mut dyn rand::RngCore) -> Self {
+        Self::random_with_config(Config::default(), rng)
+    }
+
+    /// Creates a simulation out of given config.
+    ///
+    /// Panics if the config is invalid - check it with
+    /// [`Config::validate()`] first when it comes from an untrusted
+    /// source.
+    pub fn random_with_config(config: Config, rng: &mut dyn rand::RngCore) -> Self {
+        if let Err(problem) = config.validate() {
+            panic!("invalid config: {}", problem);
+        }
+
+        let world = World::random(&config, rng);
+        let optimizer = config.ga_optimizer.build(&config);
+
         Self {
-            world: World::random(rng),
+            config,
+            world,
+            optimizer,
+            age: 0,
+            generation: 0,
         }
     }
 
+    /// Creates a simulation together with the random number generator
+    /// that should be passed to its [`Simulation::step()`].
+    ///
+    /// Given the same seed and config, the simulation evolves exactly the
+    /// same way every time it's run on the same platform.
+    pub fn from_seed(config: Config, seed: u64) -> (Self, ChaCha8Rng) {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let sim = Self::random_with_config(config, &mut rng);
+
+        (sim, rng)
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     pub fn world(&self) -> &World {
         &self.world
     }
-}
-
-#[derive(Debug)]
-pub struct World {
-    animals: Vec<Animal>,
-    food: Vec<Food>,
-}
 
-impl World {
-    pub fn random(rng: &mut dyn rand::RngCore) -> Self {
-        let animals = (0..40)
-            .map(|_| Animal::random(rng))
-            .collect();
-        let food = (0..60)
-            .map(|_| Food::random(rng))
-            .collect();
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
 
-        Self { animals, food }
+    /// Returns how many generations have been evolved so far.
+    pub fn generation(&self) -> usize {
+        self.generation
     }
 
-    pub fn animals(&self) -> &[Animal] {
-        &self.animals
+    pub fn generation_length(&self) -> usize {
+        self.config.sim_generation_length
     }
 
-    pub fn food(&self) -> &[Food] {
-        &self.food
+    pub fn set_generation_length(&mut self, generation_length: usize) {
+        assert!(generation_length > 0);
+
+        self.config.sim_generation_length = generation_length;
     }
-}
 
-#[derive(Debug)]
-pub struct Animal {
-    position: nalgebra::Point2<f32>,
-    rotation: nalgebra::Rotation2<f32>,
-    speed: f32,
-}
+    /// Advances the world by a single tick, evolving a new generation of
+    /// animals once the current one has lived for `sim_generation_length`
+    /// steps - in which case statistics of the generation that has just
+    /// ended are returned.
+    pub fn step(&mut self, rng: &mut dyn rand::RngCore) -> Option<Statistics> {
+        self.process_collisions(rng);
+        self.process_brains();
+        self.process_movements();
 
-impl Animal {
-    pub fn random(rng: &mut dyn rand::RngCore) -> Self {
-        use rand::Rng;
-        Self {
-            position: rng.gen(),
-            rotation: rng.gen(),
-            speed: 0.02,
+        self.age += 1;
+
+        if self.age >= self.config.sim_generation_length {
+            Some(self.evolve(rng))
+        } else {
+            None
         }
     }
 
-    pub fn position(&self) -> nalgebra::Point2<f32> {
-        self.position
+    /// Fast-forwards until the end of the current generation.
+    pub fn train(&mut self, rng: &mut dyn rand::RngCore) -> Statistics {
+        loop {
+            if let Some(statistics) = self.step(rng) {
+                return statistics;
+            }
+        }
     }
 
-    pub fn rotation(&self) -> nalgebra::Rotation2<f32> {
-        self.rotation
+    /// Replaces all animals with offspring of the current generation,
+    /// favouring the ones that have eaten the most.
+    fn evolve(&mut self, rng: &mut dyn rand::RngCore) -> Statistics {
+        use rand::Rng;
+
+        self.age = 0;
+        self.generation += 1;
+
+        let current_population: Vec<_> = self
+            .world
+            .animals
+            .iter()
+            .map(AnimalIndividual::from_animal)
+            .collect();
+
+        let (evolved_population, statistics) = self.optimizer.evolve(rng, &current_population);
+
+        self.world.animals = evolved_population
+            .into_iter()
+            .map(|individual| individual.into_animal(&self.config, rng))
+            .collect();
+
+        // Gives the new generation a fresh start, so that animals don't
+        // begin their lives right next to the food eaten by their parents
+        for food in &mut self.world.food {
+            food.position = rng.gen();
+        }
+
+        statistics
     }
-}
 
-#[derive(Debug)]
-pub struct Food {
-    position: nalgebra::Point2<f32>,
-}
+    /// Lets every animal look around and decide how to move.
+    fn process_brains(&mut self) {
+        for animal in &mut self.world.animals {
+            let vision = animal.eye.process_vision(
+                animal.position,
+                animal.rotation,
+                &self.world.food,
+            );
 
-impl Food {
-    pub fn random(rng: &mut dyn rand::RngCore) -> Self {
+            let (speed, rotation) = animal.brain.propagate(&self.config, vision);
+
+            animal.speed = (animal.speed + speed)
+                .clamp(self.config.sim_speed_min, self.config.sim_speed_max);
+            animal.rotation =
+                nalgebra::Rotation2::new(animal.rotation.angle() + rotation);
+        }
+    }
+
+    /// Lets animals eat the food they've bumped into; eaten food is
+    /// moved somewhere else, so the amount of food in the world stays
+    /// constant.
+    fn process_collisions(&mut self, rng: &mut dyn rand::RngCore) {
         use rand::Rng;
-        Self {
-            position: rng.gen(),
+
+        let food_radius = self.world.food_radius;
+
+        for animal in &mut self.world.animals {
+            for food in &mut self.world.food {
+                let distance = nalgebra::distance(&animal.position, &food.position);
+
+                if distance <= food_radius {
+                    animal.satiation += 1;
+                    food.position = rng.gen();
+                }
+            }
         }
     }
 
-    pub fn position(&self) -> nalgebra::Point2<f32> {
-        self.position
+    fn process_movements(&mut self) {
+        let topology = self.world.topology;
+
+        for animal in &mut self.world.animals {
+            // A rotation of zero means "facing right", the same
+            // convention the frontend uses when drawing animals.
+            animal.position += animal.rotation * nalgebra::Vector2::new(animal.speed, 0.0);
+
+            topology.apply(&mut animal.position, &mut animal.rotation);
+        }
     }
 }
 
-
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    mod step {
+        use super::*;
+
+        #[test]
+        fn moves_animals_along_their_rotation() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = Simulation::random(&mut rng);
+
+            let before: Vec<_> = sim
+                .world()
+                .animals()
+                .iter()
+                .map(|animal| animal.position())
+                .collect();
+
+            sim.step(&mut rng);
+
+            for (animal, before) in sim.world().animals().iter().zip(before) {
+                let mut expected =
+                    before + animal.rotation() * nalgebra::Vector2::new(animal.speed(), 0.0);
+
+                Topology::Torus.apply(&mut expected, &mut animal.rotation());
+
+                approx::assert_relative_eq!(animal.position(), expected);
+            }
+        }
+
+        #[test]
+        fn keeps_animals_inside_the_world() {
+            for &topology in &[Topology::Torus, Topology::Reflect, Topology::Clamp] {
+                let mut rng = ChaCha8Rng::from_seed(Default::default());
+                let config = Config {
+                    world_topology: topology,
+                    ..Default::default()
+                };
+                let mut sim = Simulation::random_with_config(config, &mut rng);
+
+                for _ in 0..100 {
+                    sim.step(&mut rng);
+                }
+
+                for animal in sim.world().animals() {
+                    let position = animal.position();
+
+                    assert!((0.0..=1.0).contains(&position.x), "{:?}", topology);
+                    assert!((0.0..=1.0).contains(&position.y), "{:?}", topology);
+                }
+            }
+        }
+
+        #[test]
+        fn keeps_uneaten_food_in_place() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                food_radius: 0.0,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            let before: Vec<_> = sim
+                .world()
+                .food()
+                .iter()
+                .map(|food| food.position())
+                .collect();
+
+            sim.step(&mut rng);
+
+            let after: Vec<_> = sim
+                .world()
+                .food()
+                .iter()
+                .map(|food| food.position())
+                .collect();
+
+            assert_eq!(before, after);
+        }
+    }
+
+    mod process_collisions {
+        use super::*;
+
+        fn simulation(config: Config, animal: (f32, f32), food: (f32, f32)) -> Simulation {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            sim.world.animals.truncate(1);
+            sim.world.animals[0].position = nalgebra::Point2::new(animal.0, animal.1);
+
+            sim.world.food.truncate(1);
+            sim.world.food[0].position = nalgebra::Point2::new(food.0, food.1);
+
+            sim
+        }
+
+        #[test]
+        fn animal_eats_food_within_radius() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = simulation(Config::default(), (0.5, 0.5), (0.505, 0.5));
+
+            sim.process_collisions(&mut rng);
+
+            assert_eq!(sim.world().animals()[0].satiation(), 1);
+            assert_ne!(
+                sim.world().food()[0].position(),
+                nalgebra::Point2::new(0.505, 0.5)
+            );
+        }
+
+        #[test]
+        fn animal_ignores_food_outside_radius() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = simulation(Config::default(), (0.5, 0.5), (0.52, 0.5));
+
+            sim.process_collisions(&mut rng);
+
+            assert_eq!(sim.world().animals()[0].satiation(), 0);
+            assert_eq!(
+                sim.world().food()[0].position(),
+                nalgebra::Point2::new(0.52, 0.5)
+            );
+        }
+
+        #[test]
+        fn respects_configured_radius() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                food_radius: 0.05,
+                ..Default::default()
+            };
+            let mut sim = simulation(config, (0.5, 0.5), (0.52, 0.5));
+
+            sim.process_collisions(&mut rng);
+
+            assert_eq!(sim.world().animals()[0].satiation(), 1);
+        }
+
+        #[test]
+        fn respects_radius_changed_later() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = simulation(Config::default(), (0.5, 0.5), (0.52, 0.5));
+
+            sim.world_mut().set_food_radius(0.05);
+            sim.process_collisions(&mut rng);
+
+            assert_eq!(sim.world().animals()[0].satiation(), 1);
+        }
+    }
+
+    mod evolve {
+        use super::*;
+
+        #[test]
+        fn happens_after_generation_length_steps() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                sim_generation_length: 10,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            for _ in 0..9 {
+                assert!(sim.step(&mut rng).is_none());
+            }
+
+            assert_eq!(sim.generation(), 0);
+            assert!(sim.step(&mut rng).is_some());
+            assert_eq!(sim.generation(), 1);
+            assert_eq!(sim.world().animals().len(), 40);
+        }
+
+        #[test]
+        fn respects_configured_world_size() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                world_animals: 5,
+                world_foods: 7,
+                sim_generation_length: 1,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            sim.step(&mut rng);
+
+            assert_eq!(sim.generation(), 1);
+            assert_eq!(sim.world().animals().len(), 5);
+            assert_eq!(sim.world().food().len(), 7);
+        }
+
+        #[test]
+        fn reports_satiation_of_parents() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = Simulation::random(&mut rng);
+
+            for (idx, animal) in sim.world.animals.iter_mut().enumerate() {
+                animal.satiation = idx % 5;
+            }
+
+            let statistics = sim.evolve(&mut rng);
+
+            approx::assert_relative_eq!(statistics.min_fitness(), 0.0);
+            approx::assert_relative_eq!(statistics.max_fitness(), 4.0);
+            approx::assert_relative_eq!(statistics.mean_fitness(), 2.0);
+        }
+
+        #[test]
+        fn train_runs_until_the_end_of_generation() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                sim_generation_length: 10,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            sim.step(&mut rng);
+            sim.train(&mut rng);
+
+            assert_eq!(sim.generation(), 1);
+            assert_eq!(sim.age, 0);
+        }
+
+        #[test]
+        fn works_with_cma_es() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                ga_optimizer: OptimizerKind::CmaEs,
+                sim_generation_length: 10,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+            let brain: Vec<_> = sim.world.animals[0].as_chromosome().into_iter().collect();
+
+            for _ in 0..3 {
+                sim.train(&mut rng);
+            }
+
+            assert_eq!(sim.generation(), 3);
+            assert_eq!(sim.world().animals().len(), 40);
+            let evolved_brain: Vec<_> = sim.world.animals[0].as_chromosome().into_iter().collect();
+
+            assert_ne!(evolved_brain, brain);
+        }
+
+        #[test]
+        fn carries_over_the_elite() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = Config {
+                ga_elitism: 1,
+                ..Default::default()
+            };
+            let mut sim = Simulation::random_with_config(config, &mut rng);
+
+            sim.world.animals[7].satiation = 10;
+            let best = sim.world.animals[7].as_chromosome();
+
+            sim.evolve(&mut rng);
+
+            let actual: Vec<_> = sim.world.animals[0].as_chromosome().into_iter().collect();
+            let expected: Vec<_> = best.into_iter().collect();
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn resets_satiation_of_offspring() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = Simulation::random(&mut rng);
+
+            for animal in &mut sim.world.animals {
+                animal.satiation = 5;
+            }
+
+            sim.evolve(&mut rng);
+
+            assert!(sim.world().animals().iter().all(|a| a.satiation() == 0));
+        }
+
+        #[test]
+        fn passes_brains_down_to_offspring() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut sim = Simulation::random(&mut rng);
+
+            let parents: Vec<Vec<f32>> = sim
+                .world()
+                .animals()
+                .iter()
+                .map(|animal| animal.as_chromosome().into_iter().collect())
+                .collect();
+
+            sim.evolve(&mut rng);
+
+            // With a low mutation chance, most of each child's genes
+            // must have been inherited from one of the parents
+            for animal in sim.world().animals() {
+                let inherited = animal
+                    .as_chromosome()
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, gene)| parents.iter().any(|parent| parent[*idx] == **gene))
+                    .count();
+
+                assert!(inherited as f32 > 0.9 * animal.as_chromosome().len() as f32);
+            }
+        }
+    }
+
+    mod from_seed {
+        use super::*;
+
+        /// Captures every bit of the world's state that's visible from
+        /// the outside.
+        fn snapshot(sim: &Simulation) -> Vec<u32> {
+            let animals = sim.world().animals().iter().flat_map(|animal| {
+                vec![
+                    animal.position().x.to_bits(),
+                    animal.position().y.to_bits(),
+                    animal.rotation().angle().to_bits(),
+                    animal.speed().to_bits(),
+                    animal.satiation() as u32,
+                ]
+            });
+
+            let food = sim
+                .world()
+                .food()
+                .iter()
+                .flat_map(|food| vec![food.position().x.to_bits(), food.position().y.to_bits()]);
+
+            animals.chain(food).collect()
+        }
+
+        fn run(seed: u64) -> Vec<Vec<u32>> {
+            let config = Config {
+                sim_generation_length: 50,
+                ..Default::default()
+            };
+            let (mut sim, mut rng) = Simulation::from_seed(config, seed);
+
+            (0..200)
+                .map(|_| {
+                    sim.step(&mut rng);
+                    snapshot(&sim)
+                })
+                .collect()
+        }
+
+        #[test]
+        fn same_seed_gives_identical_runs() {
+            assert_eq!(run(42), run(42));
+        }
+
+        #[test]
+        fn different_seeds_give_different_runs() {
+            assert_ne!(run(42), run(43));
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use crate::*;
+use ga::optimizer::Optimizer;
+use serde::{Deserialize, Serialize};
+
+/// Describes which algorithm evolves the animals' brains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizerKind {
+    /// Genetic algorithm with roulette wheel selection, uniform crossover
+    /// and Gaussian mutation, tuned through the `ga_mutation_*` and
+    /// `ga_elitism` fields of [`Config`] (`ga_cma_sigma` is ignored).
+    GeneticAlgorithm,
+
+    /// CMA-ES, with its initial step size taken from
+    /// [`Config::ga_cma_sigma`]; it adapts its own mutation and keeps no
+    /// elite, so `ga_mutation_*` and `ga_elitism` are ignored.
+    CmaEs,
+}
+
+impl Default for OptimizerKind {
+    fn default() -> Self {
+        Self::GeneticAlgorithm
+    }
+}
+
+impl OptimizerKind {
+    pub(crate) fn build(
+        self,
+        config: &Config,
+    ) -> Box<dyn Optimizer<AnimalIndividual>> {
+        match self {
+            Self::GeneticAlgorithm => {
+                let ga = ga::GeneticAlgorithm::new(
+                    ga::selection::RouletteWheelSelection::new(),
+                    ga::crossover::UniformCrossover::new(),
+                    ga::mutation::GaussianMutation::new(
+                        config.ga_mutation_chance,
+                        config.ga_mutation_coeff,
+                    ),
+                )
+                .with_replacement(ga::replacement::Replacement::Generational {
+                    elitism: config.ga_elitism,
+                });
+
+                Box::new(ga)
+            }
+
+            Self::CmaEs => Box::new(ga::cma_es::CmaEs::new(config.ga_cma_sigma)),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+/// Describes what happens to animals reaching the edge of the world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topology {
+    /// Animals leaving through one edge reappear at the opposite one.
+    Torus,
+
+    /// Edges act as walls that animals bounce off of.
+    Reflect,
+
+    /// Edges act as walls that animals get stuck on until they turn
+    /// around.
+    Clamp,
+}
+
+impl Default for Topology {
+    fn default() -> Self {
+        Self::Torus
+    }
+}
+
+impl Topology {
+    /// Brings `position` back into the unit square, adjusting `rotation`
+    /// if the animal has bounced off of a wall.
+    pub(crate) fn apply(
+        self,
+        position: &mut nalgebra::Point2<f32>,
+        rotation: &mut nalgebra::Rotation2<f32>,
+    ) {
+        match self {
+            Self::Torus => {
+                position.x = position.x.rem_euclid(1.0);
+                position.y = position.y.rem_euclid(1.0);
+            }
+
+            Self::Reflect => {
+                let mut angle = rotation.angle();
+
+                if position.x < 0.0 || position.x > 1.0 {
+                    position.x = reflect(position.x);
+                    angle = PI - angle;
+                }
+
+                if position.y < 0.0 || position.y > 1.0 {
+                    position.y = reflect(position.y);
+                    angle = -angle;
+                }
+
+                *rotation = nalgebra::Rotation2::new(angle);
+            }
+
+            Self::Clamp => {
+                position.x = position.x.clamp(0.0, 1.0);
+                position.y = position.y.clamp(0.0, 1.0);
+            }
+        }
+    }
+}
+
+/// Mirrors a coordinate that has escaped the `0.0..=1.0` range back
+/// into it.
+fn reflect(coord: f32) -> f32 {
+    let coord = if coord < 0.0 { -coord } else { 2.0 - coord };
+
+    // Extremely fast animals could overshoot the opposite wall too:
+    coord.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    fn apply(topology: Topology, x: f32, y: f32, angle: f32) -> (f32, f32, f32) {
+        let mut position = nalgebra::Point2::new(x, y);
+        let mut rotation = nalgebra::Rotation2::new(angle);
+
+        topology.apply(&mut position, &mut rotation);
+
+        (position.x, position.y, rotation.angle())
+    }
+
+    mod torus {
+        use super::*;
+
+        #[test]
+        fn keeps_positions_inside_the_world_intact() {
+            let actual = apply(Topology::Torus, 0.25, 0.75, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.25);
+            approx::assert_relative_eq!(actual.1, 0.75);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+
+        #[test]
+        fn wraps_positions_around_the_edges() {
+            let actual = apply(Topology::Torus, 1.25, -0.25, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.25);
+            approx::assert_relative_eq!(actual.1, 0.75);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+    }
+
+    mod reflect {
+        use super::*;
+
+        #[test]
+        fn keeps_positions_inside_the_world_intact() {
+            let actual = apply(Topology::Reflect, 0.25, 0.75, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.25);
+            approx::assert_relative_eq!(actual.1, 0.75);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+
+        #[test]
+        fn bounces_off_vertical_walls() {
+            let actual = apply(Topology::Reflect, 1.25, 0.5, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.75);
+            approx::assert_relative_eq!(actual.1, 0.5);
+            approx::assert_relative_eq!(actual.2, 3.0 * FRAC_PI_4);
+        }
+
+        #[test]
+        fn bounces_off_horizontal_walls() {
+            let actual = apply(Topology::Reflect, 0.5, -0.25, -FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.5);
+            approx::assert_relative_eq!(actual.1, 0.25);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+
+        #[test]
+        fn bounces_off_corners() {
+            let actual = apply(Topology::Reflect, -0.25, 1.25, 3.0 * FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.25);
+            approx::assert_relative_eq!(actual.1, 0.75);
+            approx::assert_relative_eq!(actual.2, -FRAC_PI_4);
+        }
+    }
+
+    mod clamp {
+        use super::*;
+
+        #[test]
+        fn keeps_positions_inside_the_world_intact() {
+            let actual = apply(Topology::Clamp, 0.25, 0.75, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 0.25);
+            approx::assert_relative_eq!(actual.1, 0.75);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+
+        #[test]
+        fn stops_positions_at_the_edges() {
+            let actual = apply(Topology::Clamp, 1.25, -0.25, FRAC_PI_4);
+
+            approx::assert_relative_eq!(actual.0, 1.0);
+            approx::assert_relative_eq!(actual.1, 0.0);
+            approx::assert_relative_eq!(actual.2, FRAC_PI_4);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::*;
+
+#[derive(Debug)]
+pub struct World {
+    pub(crate) animals: Vec<Animal>,
+    pub(crate) food: Vec<Food>,
+    pub(crate) topology: Topology,
+    /// How close an animal has to get to a food item to eat it
+    pub(crate) food_radius: f32,
+}
+
+impl World {
+    pub fn random(config: &Config, rng: &mut dyn rand::RngCore) -> Self {
+        let animals = (0..config.world_animals)
+            .map(|_| Animal::random(config, rng))
+            .collect();
+        let food = (0..config.world_foods)
+            .map(|_| Food::random(rng))
+            .collect();
+
+        Self {
+            animals,
+            food,
+            topology: config.world_topology,
+            food_radius: config.food_radius,
+        }
+    }
+
+    pub fn animals(&self) -> &[Animal] {
+        &self.animals
+    }
+
+    pub fn food(&self) -> &[Food] {
+        &self.food
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    pub fn food_radius(&self) -> f32 {
+        self.food_radius
+    }
+
+    pub fn set_food_radius(&mut self, food_radius: f32) {
+        assert!(food_radius >= 0.0);
+
+        self.food_radius = food_radius;
+    }
+}
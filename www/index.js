@@ -1,9 +1,12 @@
 import * as sim from "lib-simulation-wasm";
 
-const simulation = new sim.Simulation();
-const world = simulation.world();
+// Passing `?seed=1234` replays a previously seen simulation
+const params = new URLSearchParams(window.location.search);
+const seed = params.has('seed') ? BigInt(params.get('seed')) : undefined;
 
-console.log(world);
+const simulation = new sim.Simulation(undefined, seed);
+
+console.log('seed =', simulation.seed());
 
 const viewport = document.getElementById('viewport');
 const viewportWidth = viewport.width;
@@ -45,13 +48,46 @@ CanvasRenderingContext2D.prototype.drawTriangle =
     this.fill();
 };
 
+CanvasRenderingContext2D.prototype.drawCircle =
+    function (x, y, radius) {
+    this.beginPath();
+    this.arc(x, y, radius, 0, 2.0 * Math.PI);
+
+    this.fillStyle = 'rgb(0, 200, 0)';
+    this.fill();
+};
+
 // ctxt.fillStyle = 'rgb(0, 0, 0)';
 
-for (const animal of simulation.world().animals) {
-    ctxt.drawTriangle(
-        animal.x * viewportWidth,
-        animal.y * viewportHeight,
-        0.01 * viewportWidth,
-        animal.rotation,
-    );
+function redraw() {
+    ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
+
+    const statistics = simulation.step();
+
+    if (statistics) {
+        console.log(statistics);
+    }
+
+    const world = simulation.world();
+
+    for (const food of world.food) {
+        ctxt.drawCircle(
+            food.x * viewportWidth,
+            food.y * viewportHeight,
+            0.005 * viewportWidth,
+        );
+    }
+
+    for (const animal of world.animals) {
+        ctxt.drawTriangle(
+            animal.x * viewportWidth,
+            animal.y * viewportHeight,
+            0.01 * viewportWidth,
+            animal.rotation,
+        );
+    }
+
+    requestAnimationFrame(redraw);
 }
+
+redraw();